@@ -42,6 +42,9 @@ impl EditGuild {
     /// Set the amount of time a user is to be moved to the AFK channel -
     /// configured via [`afk_channel`] - after being AFK.
     ///
+    /// **Note**: Discord only accepts a timeout of `60`, `300`, `900`, `1800`
+    /// or `3600` seconds. Editing the guild fails with any other value.
+    ///
     /// [`afk_channel`]: Self::afk_channel
     pub fn afk_timeout(&mut self, timeout: u64) -> &mut Self {
         self.0.insert(
@@ -51,8 +54,46 @@ impl EditGuild {
         self
     }
 
+    /// Set the banner of the guild. Pass `None` to remove the banner.
+    ///
+    /// Requires that the guild have the `BANNER` feature enabled.
+    /// You can check this through a guild's [`features`] list.
+    ///
+    /// The same image restrictions as for [`icon`] apply.
+    ///
+    /// [`features`]: crate::model::guild::Guild::features
+    /// [`icon`]: Self::icon
+    pub fn banner(&mut self, banner: Option<&str>) -> &mut Self {
+        let banner = banner.map_or(Value::Null, |x| Value::String(x.to_string()));
+        self.0.insert("banner", banner);
+        self
+    }
+
+    /// Set the level of notifications members receive by default for
+    /// messages sent in the guild.
+    pub fn default_message_notifications(&mut self, level: DefaultMessageNotificationLevel) -> &mut Self {
+        self.0.insert(
+            "default_message_notifications",
+            Value::Number(Number::from(level.num())),
+        );
+        self
+    }
+
+    /// Set which members' messages are scanned for explicit content.
+    pub fn explicit_content_filter(&mut self, filter: ExplicitContentFilter) -> &mut Self {
+        self.0.insert(
+            "explicit_content_filter",
+            Value::Number(Number::from(filter.num())),
+        );
+        self
+    }
+
     /// Set the icon of the guild. Pass `None` to remove the icon.
     ///
+    /// The icon must be a `png`, `jpeg`, or `gif` data URI of at most
+    /// [`GUILD_IMAGE_MAX_SIZE`] bytes, otherwise editing the guild fails with
+    /// a [`ModelError::InvalidImageData`].
+    ///
     /// # Examples
     ///
     /// Using the utility function - [`utils::read_image`] - to read an image
@@ -79,6 +120,7 @@ impl EditGuild {
     /// ```
     ///
     /// [`utils::read_image`]: crate::utils::read_image
+    /// [`GUILD_IMAGE_MAX_SIZE`]: crate::constants::GUILD_IMAGE_MAX_SIZE
     pub fn icon(&mut self, icon: Option<&str>) -> &mut Self {
         self.0.insert(
             "icon",
@@ -142,13 +184,51 @@ impl EditGuild {
     /// Requires that the guild have the `INVITE_SPLASH` feature enabled.
     /// You can check this through a guild's [`features`] list.
     ///
+    /// The same image restrictions as for [`icon`] apply.
+    ///
     /// [`features`]: crate::model::guild::Guild::features
+    /// [`icon`]: Self::icon
     pub fn splash(&mut self, splash: Option<&str>) -> &mut Self {
         let splash = splash.map_or(Value::Null, |x| Value::String(x.to_string()));
         self.0.insert("splash", splash);
         self
     }
 
+    /// Set the channel that system messages, such as for members joining or
+    /// boosting the guild, are sent to. Pass `None` to disable them.
+    ///
+    /// The library does not check if the channel is a valid text channel.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{http::Http, model::id::{ChannelId, GuildId}};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let http = Http::default();
+    /// #     let mut guild = GuildId(0).to_partial_guild(&http).await?;
+    /// // assuming a `guild` has already been bound
+    ///
+    /// guild.edit(&http, |g| g.system_channel(Some(ChannelId(7)))).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn system_channel<C: Into<ChannelId>>(&mut self, channel: Option<C>) -> &mut Self {
+        self._system_channel(channel.map(Into::into));
+        self
+    }
+
+    fn _system_channel(&mut self, channel: Option<ChannelId>) {
+        self.0.insert(
+            "system_channel_id",
+            match channel {
+                Some(channel) => Value::String(channel.0.to_string()),
+                None => Value::Null,
+            },
+        );
+    }
+
     /// Set the verification level of the guild. This can restrict what a
     /// user must have prior to being able to send messages in a guild.
     ///
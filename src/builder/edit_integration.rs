@@ -0,0 +1,35 @@
+use crate::internal::prelude::*;
+use std::collections::HashMap;
+
+/// A builder which edits the settings of an [`Integration`], to be used in
+/// conjunction with [`Integration::edit`].
+///
+/// [`Integration`]: crate::model::guild::Integration
+/// [`Integration::edit`]: crate::model::guild::Integration::edit
+#[derive(Clone, Debug, Default)]
+pub struct EditIntegration(pub HashMap<&'static str, Value>);
+
+impl EditIntegration {
+    /// What to do with subscribers whose subscription has expired: `0` to
+    /// remove their role, or `1` to kick them.
+    pub fn expire_behaviour(&mut self, behaviour: u64) -> &mut Self {
+        self.0.insert("expire_behavior", Value::Number(Number::from(behaviour)));
+        self
+    }
+
+    /// The number of days subscribers are kept after their subscription has
+    /// expired, before the [`expire_behaviour`] is applied.
+    ///
+    /// [`expire_behaviour`]: Self::expire_behaviour
+    pub fn expire_grace_period(&mut self, days: u64) -> &mut Self {
+        self.0.insert("expire_grace_period", Value::Number(Number::from(days)));
+        self
+    }
+
+    /// Whether emoticons should be synced for the integration. This is only
+    /// supported by Twitch integrations.
+    pub fn enable_emoticons(&mut self, enable: bool) -> &mut Self {
+        self.0.insert("enable_emoticons", Value::Bool(enable));
+        self
+    }
+}
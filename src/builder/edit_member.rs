@@ -63,6 +63,12 @@ impl EditMember {
 
     /// The Id of the voice channel to move the member to.
     ///
+    /// This can be combined with other edits, such as unmuting the member, to
+    /// apply them all in a single request.
+    ///
+    /// **Note**: The member must already be connected to a voice channel, or
+    /// Discord will reject the request.
+    ///
     /// Requires the [Move Members] permission.
     ///
     /// [Move Members]: crate::model::permissions::Permissions::MOVE_MEMBERS
@@ -3,6 +3,7 @@ use crate::model::{
     guild::Role,
     Permissions
 };
+use crate::utils::Colour;
 use std::collections::HashMap;
 
 /// A builder to create or edit a [`Role`] for use via a number of model methods.
@@ -70,11 +71,28 @@ impl EditRole {
     }
 
     /// Sets the colour of the role.
-    pub fn colour(&mut self, colour: u64) -> &mut Self {
-        self.0.insert("color", Value::Number(Number::from(colour)));
+    ///
+    /// This is an alias of [`colour`].
+    ///
+    /// [`colour`]: Self::colour
+    #[inline]
+    pub fn color<C: Into<Colour>>(&mut self, colour: C) -> &mut Self {
+        self.colour(colour);
         self
     }
 
+    /// Sets the colour of the role, either from a [`Colour`] such as
+    /// [`Colour::BLURPLE`] or [`Colour::from_rgb`], or from a raw integer.
+    #[inline]
+    pub fn colour<C: Into<Colour>>(&mut self, colour: C) -> &mut Self {
+        self._colour(colour.into());
+        self
+    }
+
+    fn _colour(&mut self, colour: Colour) {
+        self.0.insert("color", Value::Number(Number::from(u64::from(colour.0))));
+    }
+
     /// Whether or not to hoist the role above lower-positioned role in the user
     /// list.
     pub fn hoist(&mut self, hoist: bool) -> &mut Self {
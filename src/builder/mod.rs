@@ -12,6 +12,7 @@ mod create_message;
 mod create_allowed_mentions;
 mod edit_channel;
 mod edit_guild;
+mod edit_integration;
 mod edit_member;
 mod edit_message;
 mod edit_profile;
@@ -28,6 +29,7 @@ pub use self::{
     create_allowed_mentions::ParseValue,
     edit_channel::EditChannel,
     edit_guild::EditGuild,
+    edit_integration::EditIntegration,
     edit_member::EditMember,
     edit_message::EditMessage,
     edit_profile::EditProfile,
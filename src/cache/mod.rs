@@ -40,6 +40,7 @@ use std::collections::{
     VecDeque,
 };
 use std::default::Default;
+use std::time::{Duration, Instant};
 use async_trait::async_trait;
 use tracing::instrument;
 
@@ -155,6 +156,9 @@ pub struct Cache {
     /// inserted into the cache. When a maximum number of messages are in a
     /// channel's cache, we can pop the front and remove that ID from the cache.
     pub(crate) message_queue: RwLock<HashMap<ChannelId, VecDeque<MessageId>>>,
+    /// Prune counts retrieved over the REST API, keyed by guild and number
+    /// of days, along with the time at which they were retrieved.
+    pub(crate) prune_counts: RwLock<HashMap<(GuildId, u16), (Instant, GuildPrune)>>,
     /// The settings for the cache.
     settings: RwLock<Settings>,
 }
@@ -906,6 +910,23 @@ impl Cache {
         e.update(self).await
     }
 
+    /// Retrieves the prune count last stored for the guild and number of
+    /// days, if it was stored no longer than `max_age` ago.
+    pub(crate) async fn prune_count(&self, guild_id: GuildId, days: u16, max_age: Duration) -> Option<GuildPrune> {
+        let prune_counts = self.prune_counts.read().await;
+        let (retrieved_at, prune) = prune_counts.get(&(guild_id, days))?;
+
+        if retrieved_at.elapsed() <= max_age {
+            Some(*prune)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) async fn update_prune_count(&self, guild_id: GuildId, days: u16, prune: GuildPrune) {
+        self.prune_counts.write().await.insert((guild_id, days), (Instant::now(), prune));
+    }
+
     pub(crate) async fn update_user_entry(&self, user: &User) {
         match self.users.write().await.entry(user.id) {
             Entry::Vacant(e) => {
@@ -933,6 +954,7 @@ impl Default for Cache {
             user: RwLock::new(CurrentUser::default()),
             users: RwLock::new(HashMap::default()),
             message_queue: RwLock::new(HashMap::default()),
+            prune_counts: RwLock::new(HashMap::default()),
         }
     }
 }
@@ -969,6 +991,7 @@ mod test {
                     bot: false,
                     discriminator: 1,
                     name: "user 1".to_owned(),
+                    global_name: None,
                 },
                 channel_id: ChannelId(2),
                 guild_id: Some(GuildId(1)),
@@ -1063,7 +1086,7 @@ mod test {
                     explicit_content_filter: ExplicitContentFilter::None,
                     features: vec![],
                     icon: None,
-                    joined_at: datetime,
+                    joined_at: Some(datetime),
                     large: false,
                     member_count: 0,
                     members: HashMap::new(),
@@ -1075,6 +1098,7 @@ mod test {
                     roles: HashMap::new(),
                     splash: None,
                     system_channel_id: None,
+                    unavailable: false,
                     verification_level: VerificationLevel::Low,
                     voice_states: HashMap::new(),
                     description: None,
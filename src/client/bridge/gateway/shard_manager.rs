@@ -14,10 +14,12 @@ use super::{
     ShardId,
     ShardManagerMessage,
     ShardManagerMonitor,
+    ShardMessenger,
     ShardQueuer,
     ShardQueuerMessage,
     ShardRunnerInfo,
 };
+use crate::model::id::GuildId;
 use tracing::{info, warn, instrument};
 
 use typemap_rev::TypeMap;
@@ -169,6 +171,23 @@ impl ShardManager {
         self.runners.lock().await.contains_key(&shard_id)
     }
 
+    /// Returns the messenger of the shard responsible for the given guild, as
+    /// determined by [`utils::shard_id`] and the total number of shards.
+    ///
+    /// Returns `None` if that shard is not managed by this instance, or has
+    /// not been initiated yet.
+    ///
+    /// [`utils::shard_id`]: crate::utils::shard_id
+    pub async fn guild_messenger(&self, guild_id: GuildId) -> Option<ShardMessenger> {
+        if self.shard_total == 0 {
+            return None;
+        }
+
+        let shard_id = ShardId(crate::utils::shard_id(guild_id.0, self.shard_total));
+
+        self.runners.lock().await.get(&shard_id).map(|runner| runner.runner_tx.clone())
+    }
+
     /// Initializes all shards that the manager is responsible for.
     ///
     /// This will communicate shard boots with the [`ShardQueuer`] so that they
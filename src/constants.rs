@@ -5,6 +5,9 @@ pub const EMBED_MAX_LENGTH: usize = 6000;
 /// The gateway version used by the library. The gateway URI is retrieved via
 /// the REST API.
 pub const GATEWAY_VERSION: u8 = 8;
+/// The maximum size, in bytes, of a decoded guild icon, banner, or splash
+/// image.
+pub const GUILD_IMAGE_MAX_SIZE: usize = 10 * 1024 * 1024;
 /// The large threshold to send on identify.
 pub const LARGE_THRESHOLD: u8 = 250;
 /// The maximum unicode code points allowed within a message by Discord.
@@ -12,6 +12,7 @@ use reqwest::{
 };
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
+use crate::model::utils::insert_guild_id;
 use super::{
     ratelimiting::{Ratelimiter, RatelimitedRequest},
     request::Request,
@@ -105,12 +106,35 @@ impl Http {
     pub async fn ban_user(&self, guild_id: u64, user_id: u64, delete_message_days: u8, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
-            route: RouteInfo::GuildBanUser {
+            headers: audit_log_reason(reason)?,
+            route: RouteInfo::CreateBan {
+                guild_id,
+                user_id,
                 delete_message_days: Some(delete_message_days),
-                reason: Some(&utf8_percent_encode(reason, NON_ALPHANUMERIC).to_string()),
+                delete_message_seconds: None,
+            },
+        }).await
+    }
+
+    /// Bans a [`User`] from a [`Guild`], removing their messages sent in the last
+    /// X number of seconds.
+    ///
+    /// Passing a `delete_message_seconds` of `0` is equivalent to not removing
+    /// any messages. Up to `604800` seconds (7 days) worth of messages may be
+    /// deleted.
+    ///
+    /// **Note**: Requires that you have the [Ban Members] permission.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub async fn ban_user_with_seconds(&self, guild_id: u64, user_id: u64, delete_message_seconds: u32, reason: &str) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            headers: audit_log_reason(reason)?,
+            route: RouteInfo::CreateBan {
                 guild_id,
                 user_id,
+                delete_message_days: None,
+                delete_message_seconds: Some(delete_message_seconds),
             },
         }).await
     }
@@ -158,11 +182,15 @@ impl Http {
     /// [`create_emoji`]: Guild::create_emoji
     /// [Manage Emojis]: Permissions::MANAGE_EMOJIS
     pub async fn create_emoji(&self, guild_id: u64, map: &Value) -> Result<Emoji> {
-        self.fire(Request {
+        let mut value = self.request(Request {
             body: Some(map.to_string().as_bytes()),
             headers: None,
             route: RouteInfo::CreateEmoji { guild_id },
-        }).await
+        }).await?.json::<Value>().await?;
+
+        insert_guild_id(&mut value, guild_id);
+
+        serde_json::from_value(value).map_err(From::from)
     }
 
     /// Creates a guild with the data provided.
@@ -208,6 +236,19 @@ impl Http {
         }).await
     }
 
+    /// Creates a guild from a guild template.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/guild-template#create-guild-from-guild-template
+    pub async fn create_guild_from_template(&self, code: &str, map: &Value) -> Result<PartialGuild> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateGuildFromTemplate { code },
+        }).await
+    }
+
     /// Creates an [`Integration`] for a [`Guild`].
     ///
     /// Refer to Discord's [docs] for field information.
@@ -224,6 +265,22 @@ impl Http {
         }).await
     }
 
+    /// Creates a template from the current state of a guild.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [docs]: https://discord.com/developers/docs/resources/guild-template#create-guild-template
+    pub async fn create_guild_template(&self, guild_id: u64, map: &Value) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            headers: None,
+            route: RouteInfo::CreateGuildTemplate { guild_id },
+        }).await
+    }
+
     /// Creates a [`RichInvite`] for the given [channel][`GuildChannel`].
     ///
     /// Refer to Discord's [docs] for field information.
@@ -341,9 +398,15 @@ impl Http {
 
     /// Deletes a private channel or a channel in a guild.
     pub async fn delete_channel(&self, channel_id: u64) -> Result<Channel> {
+        self.delete_channel_with_reason(channel_id, "").await
+    }
+
+    /// Deletes a private channel or a channel in a guild with a provided
+    /// reason.
+    pub async fn delete_channel_with_reason(&self, channel_id: u64, reason: &str) -> Result<Channel> {
         self.fire(Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::DeleteChannel { channel_id },
         }).await
     }
@@ -375,6 +438,15 @@ impl Http {
         }).await
     }
 
+    /// Deletes a template from a guild.
+    pub async fn delete_guild_template(&self, guild_id: u64, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::DeleteGuildTemplate { code, guild_id },
+        }).await
+    }
+
     /// Deletes an invite by code.
     pub async fn delete_invite(&self, code: &str) -> Result<Invite> {
         self.fire(Request {
@@ -483,9 +555,14 @@ impl Http {
 
     /// Deletes a role from a server. Can't remove the default everyone role.
     pub async fn delete_role(&self, guild_id: u64, role_id: u64) -> Result<()> {
+        self.delete_role_with_reason(guild_id, role_id, "").await
+    }
+
+    /// Deletes a role from a server with a provided reason.
+    pub async fn delete_role_with_reason(&self, guild_id: u64, role_id: u64, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::DeleteRole { guild_id, role_id },
         }).await
     }
@@ -564,11 +641,15 @@ impl Http {
     pub async fn edit_emoji(&self, guild_id: u64, emoji_id: u64, map: &Value) -> Result<Emoji> {
         let body = serde_json::to_vec(map)?;
 
-        self.fire(Request {
+        let mut value = self.request(Request {
             body: Some(&body),
             headers: None,
             route: RouteInfo::EditEmoji { guild_id, emoji_id },
-        }).await
+        }).await?.json::<Value>().await?;
+
+        insert_guild_id(&mut value, guild_id);
+
+        serde_json::from_value(value).map_err(From::from)
     }
 
     /// Changes guild information.
@@ -608,6 +689,17 @@ impl Http {
         }).await
     }
 
+    /// Edits the settings of a guild's integration.
+    pub async fn edit_guild_integration(&self, guild_id: u64, integration_id: u64, map: &JsonMap) -> Result<()> {
+        let body = serde_json::to_vec(map)?;
+
+        self.wind(204, Request {
+            body: Some(&body),
+            headers: None,
+            route: RouteInfo::EditGuildIntegration { guild_id, integration_id },
+        }).await
+    }
+
     /// Does specific actions to a member.
     pub async fn edit_member(&self, guild_id: u64, user_id: u64, map: &JsonMap) -> Result<Member> {
         let body = serde_json::to_vec(map)?;
@@ -972,12 +1064,36 @@ impl Http {
         }
     }
 
+    /// Gets the ban of a specific user in a guild.
+    pub async fn get_ban(&self, guild_id: u64, user_id: u64) -> Result<Ban> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetBan { guild_id, user_id },
+        }).await
+    }
+
     /// Gets all the users that are banned in specific guild.
     pub async fn get_bans(&self, guild_id: u64) -> Result<Vec<Ban>> {
+        self.get_bans_paginated(guild_id, None, None, None).await
+    }
+
+    /// Gets a page of the users that are banned in specific guild.
+    ///
+    /// Passing `before` or `after` returns the bans of users with a lower or
+    /// higher Id respectively, while `limit` caps the amount of bans returned
+    /// at up to 1000.
+    pub async fn get_bans_paginated(
+        &self,
+        guild_id: u64,
+        before: Option<u64>,
+        limit: Option<u16>,
+        after: Option<u64>,
+    ) -> Result<Vec<Ban>> {
         self.fire(Request {
             body: None,
             headers: None,
-            route: RouteInfo::GetBans { guild_id },
+            route: RouteInfo::GetBans { after, before, guild_id, limit },
         }).await
     }
 
@@ -1088,20 +1204,32 @@ impl Http {
 
     /// Gets all emojis of a guild.
     pub async fn get_emojis(&self, guild_id: u64) -> Result<Vec<Emoji>> {
-        self.fire(Request {
+        let mut value = self.request(Request {
             body: None,
             headers: None,
             route: RouteInfo::GetEmojis { guild_id },
-        }).await
+        }).await?.json::<Value>().await?;
+
+        if let Some(array) = value.as_array_mut() {
+            for emoji in array {
+                insert_guild_id(emoji, guild_id);
+            }
+        }
+
+        serde_json::from_value(value).map_err(From::from)
     }
 
     /// Gets information about an emoji in a guild.
     pub async fn get_emoji(&self, guild_id: u64, emoji_id: u64) -> Result<Emoji> {
-        self.fire(Request {
+        let mut value = self.request(Request {
             body: None,
             headers: None,
             route: RouteInfo::GetEmoji { guild_id, emoji_id },
-        }).await
+        }).await?.json::<Value>().await?;
+
+        insert_guild_id(&mut value, guild_id);
+
+        serde_json::from_value(value).map_err(From::from)
     }
 
     /// Gets current gateway.
@@ -1133,11 +1261,19 @@ impl Http {
 
     /// Gets integrations that a guild has.
     pub async fn get_guild_integrations(&self, guild_id: u64) -> Result<Vec<Integration>> {
-        self.fire(Request {
+        let mut value = self.request(Request {
             body: None,
             headers: None,
             route: RouteInfo::GetGuildIntegrations { guild_id },
-        }).await
+        }).await?.json::<Value>().await?;
+
+        if let Some(array) = value.as_array_mut() {
+            for integration in array {
+                insert_guild_id(integration, guild_id);
+            }
+        }
+
+        serde_json::from_value(value).map_err(From::from)
     }
 
     /// Gets all invites to a guild.
@@ -1149,11 +1285,11 @@ impl Http {
         }).await
     }
 
-    /// Gets a guild's vanity URL if it has one.
-    pub async fn get_guild_vanity_url(&self, guild_id: u64) -> Result<String> {
+    /// Gets a guild's vanity URL code if it has one.
+    pub async fn get_guild_vanity_url(&self, guild_id: u64) -> Result<Option<String>> {
         #[derive(Deserialize)]
         struct GuildVanityUrl {
-            code: String,
+            code: Option<String>,
         }
 
         self.request(Request {
@@ -1244,6 +1380,15 @@ impl Http {
         serde_json::from_value(value).map_err(From::from)
     }
 
+    /// Gets the templates of a guild.
+    pub async fn get_guild_templates(&self, guild_id: u64) -> Result<Vec<GuildTemplate>> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::GetGuildTemplates { guild_id },
+        }).await
+    }
+
     /// Retrieves the webhooks for the given [guild][`Guild`]'s Id.
     ///
     /// This method requires authentication.
@@ -1523,12 +1668,8 @@ impl Http {
     pub async fn kick_member_with_reason(&self, guild_id: u64, user_id: u64, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
-            route: RouteInfo::KickMember {
-                guild_id,
-                user_id,
-                reason: &utf8_percent_encode(reason, NON_ALPHANUMERIC).to_string(),
-            },
+            headers: audit_log_reason(reason)?,
+            route: RouteInfo::RemoveMember { guild_id, user_id },
         }).await
     }
 
@@ -1647,9 +1788,14 @@ impl Http {
 
     /// Unbans a user from a guild.
     pub async fn remove_ban(&self, guild_id: u64, user_id: u64) -> Result<()> {
+        self.remove_ban_with_reason(guild_id, user_id, "").await
+    }
+
+    /// Unbans a user from a guild with a provided reason.
+    pub async fn remove_ban_with_reason(&self, guild_id: u64, user_id: u64, reason: &str) -> Result<()> {
         self.wind(204, Request {
             body: None,
-            headers: None,
+            headers: audit_log_reason(reason)?,
             route: RouteInfo::RemoveBan { guild_id, user_id },
         }).await
     }
@@ -1688,6 +1834,15 @@ impl Http {
         }).await
     }
 
+    /// Syncs a guild template with the current state of its guild.
+    pub async fn sync_guild_template(&self, guild_id: u64, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            headers: None,
+            route: RouteInfo::SyncGuildTemplate { code, guild_id },
+        }).await
+    }
+
     /// Starts syncing an integration with a guild.
     pub async fn start_integration_sync(&self, guild_id: u64, integration_id: u64) -> Result<()> {
         self.wind(204, Request {
@@ -1876,6 +2031,28 @@ fn configure_client_backend(builder: ClientBuilder) -> ClientBuilder {
     builder.use_native_tls()
 }
 
+/// Builds the `X-Audit-Log-Reason` header for a request, unless the `reason`
+/// is empty.
+///
+/// Returns [`Error::ExceededLimit`] if the reason is over Discord's limit of
+/// 512 characters.
+fn audit_log_reason(reason: &str) -> Result<Option<Headers>> {
+    if reason.is_empty() {
+        return Ok(None);
+    }
+
+    if reason.chars().count() > 512 {
+        return Err(Error::ExceededLimit(reason.to_string(), 512));
+    }
+
+    let reason = utf8_percent_encode(reason, NON_ALPHANUMERIC).to_string();
+
+    let mut headers = Headers::new();
+    headers.insert("X-Audit-Log-Reason", HeaderValue::from_str(&reason).map_err(HttpError::InvalidHeader)?);
+
+    Ok(Some(headers))
+}
+
 impl AsRef<Http> for Http {
     fn as_ref(&self) -> &Http { &self }
 }
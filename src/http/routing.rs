@@ -228,6 +228,18 @@ pub enum Route {
     ///
     /// [`GuildId`]: crate::model::id::GuildId
     GuildsIdRolesId(u64),
+    /// Route for the `/guilds/:guild_id/templates` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdTemplates(u64),
+    /// Route for the `/guilds/:guild_id/templates/:code` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdTemplatesCode(u64),
     /// Route for the `/guilds/:guild_id/vanity-url` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -240,6 +252,8 @@ pub enum Route {
     ///
     /// [`GuildId`]: crate::model::id::GuildId
     GuildsIdWebhooks(u64),
+    /// Route for the `/guilds/templates/:code` path.
+    GuildsTemplatesCode,
     /// Route for the `/invites/:code` path.
     InvitesCode,
     /// Route for the `/users/:user_id` path.
@@ -413,6 +427,7 @@ impl Route {
         format!(api!("/guilds/{}/bans/{}"), guild_id, user_id)
     }
 
+    #[deprecated(since = "0.9.3", note = "use `Route::create_ban_optioned` and send the reason as an audit log header")]
     pub fn guild_ban_optioned(
         guild_id: u64,
         user_id: u64,
@@ -428,6 +443,30 @@ impl Route {
         )
     }
 
+    pub fn create_ban_optioned(
+        guild_id: u64,
+        user_id: u64,
+        delete_message_days: Option<u8>,
+        delete_message_seconds: Option<u32>,
+    ) -> String {
+        let mut s = format!(
+            api!("/guilds/{}/bans/{}?"),
+            guild_id,
+            user_id,
+        );
+
+        if let Some(days) = delete_message_days {
+            let _ = write!(s, "&delete_message_days={}", days);
+        }
+
+        if let Some(seconds) = delete_message_seconds {
+            let _ = write!(s, "&delete_message_seconds={}", seconds);
+        }
+
+        s
+    }
+
+    #[deprecated(since = "0.9.3", note = "use `Route::guild_member` and send the reason as an audit log header")]
     pub fn guild_kick_optioned(
         guild_id: u64,
         user_id: u64,
@@ -445,6 +484,32 @@ impl Route {
         format!(api!("/guilds/{}/bans"), guild_id)
     }
 
+    pub fn guild_bans_optioned(
+        guild_id: u64,
+        before: Option<u64>,
+        limit: Option<u16>,
+        after: Option<u64>,
+    ) -> String {
+        let mut s = format!(
+            api!("/guilds/{}/bans?"),
+            guild_id,
+        );
+
+        if let Some(before) = before {
+            let _ = write!(s, "&before={}", before);
+        }
+
+        if let Some(after) = after {
+            let _ = write!(s, "&after={}", after);
+        }
+
+        if let Some(limit) = limit {
+            let _ = write!(s, "&limit={}", limit);
+        }
+
+        s
+    }
+
     pub fn guild_channels(guild_id: u64) -> String {
         format!(api!("/guilds/{}/channels"), guild_id)
     }
@@ -546,6 +611,14 @@ impl Route {
         format!(api!("/guilds/{}/roles"), guild_id)
     }
 
+    pub fn guild_template(guild_id: u64, code: &str) -> String {
+        format!(api!("/guilds/{}/templates/{}"), guild_id, code)
+    }
+
+    pub fn guild_templates(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/templates"), guild_id)
+    }
+
     pub fn guild_vanity_url(guild_id: u64) -> String {
         format!(api!("/guilds/{}/vanity-url"), guild_id)
     }
@@ -558,6 +631,10 @@ impl Route {
         api!("/guilds")
     }
 
+    pub fn guilds_template(code: &str) -> String {
+        format!(api!("/guilds/templates/{}"), code)
+    }
+
     pub fn invite(code: &str) -> String {
         format!(api!("/invites/{}"), code)
     }
@@ -648,6 +725,7 @@ pub enum RouteInfo<'a> {
         role_id: u64,
         user_id: u64,
     },
+    #[deprecated(since = "0.9.3", note = "use `RouteInfo::CreateBan` and send the reason as an audit log header")]
     GuildBanUser {
         guild_id: u64,
         user_id: u64,
@@ -657,6 +735,12 @@ pub enum RouteInfo<'a> {
     BroadcastTyping {
         channel_id: u64,
     },
+    CreateBan {
+        guild_id: u64,
+        user_id: u64,
+        delete_message_days: Option<u8>,
+        delete_message_seconds: Option<u32>,
+    },
     CreateChannel {
         guild_id: u64,
     },
@@ -664,10 +748,16 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
     },
     CreateGuild,
+    CreateGuildFromTemplate {
+        code: &'a str,
+    },
     CreateGuildIntegration {
         guild_id: u64,
         integration_id: u64,
     },
+    CreateGuildTemplate {
+        guild_id: u64,
+    },
     CreateInvite {
         channel_id: u64,
     },
@@ -704,6 +794,10 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         integration_id: u64,
     },
+    DeleteGuildTemplate {
+        code: &'a str,
+        guild_id: u64,
+    },
     DeleteInvite {
         code: &'a str,
     },
@@ -760,6 +854,10 @@ pub enum RouteInfo<'a> {
     EditGuildEmbed {
         guild_id: u64,
     },
+    EditGuildIntegration {
+        guild_id: u64,
+        integration_id: u64,
+    },
     EditMember {
         guild_id: u64,
         user_id: u64,
@@ -799,8 +897,15 @@ pub enum RouteInfo<'a> {
         limit: Option<u8>,
         user_id: Option<u64>,
     },
+    GetBan {
+        guild_id: u64,
+        user_id: u64,
+    },
     GetBans {
+        after: Option<u64>,
+        before: Option<u64>,
         guild_id: u64,
+        limit: Option<u16>,
     },
     GetBotGateway,
     GetChannel {
@@ -852,6 +957,9 @@ pub enum RouteInfo<'a> {
     GetGuildRoles {
         guild_id: u64,
     },
+    GetGuildTemplates {
+        guild_id: u64,
+    },
     GetGuildVanityUrl {
         guild_id: u64,
     },
@@ -903,6 +1011,7 @@ pub enum RouteInfo<'a> {
         token: &'a str,
         webhook_id: u64,
     },
+    #[deprecated(since = "0.9.3", note = "use `RouteInfo::RemoveMember` and send the reason as an audit log header")]
     KickMember {
         guild_id: u64,
         user_id: u64,
@@ -922,6 +1031,10 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         user_id: u64,
     },
+    RemoveMember {
+        guild_id: u64,
+        user_id: u64,
+    },
     RemoveMemberRole {
         guild_id: u64,
         role_id: u64,
@@ -938,6 +1051,10 @@ pub enum RouteInfo<'a> {
     StatusIncidentsUnresolved,
     StatusMaintenancesActive,
     StatusMaintenancesUpcoming,
+    SyncGuildTemplate {
+        code: &'a str,
+        guild_id: u64,
+    },
     UnpinMessage {
         channel_id: u64,
         message_id: u64,
@@ -952,6 +1069,7 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersIdRolesId(guild_id),
                 Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
             ),
+            #[allow(deprecated)]
             RouteInfo::GuildBanUser {
                 guild_id,
                 delete_message_days,
@@ -973,6 +1091,21 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdTyping(channel_id),
                 Cow::from(Route::channel_typing(channel_id)),
             ),
+            RouteInfo::CreateBan {
+                guild_id,
+                user_id,
+                delete_message_days,
+                delete_message_seconds,
+            } => (
+                LightMethod::Put,
+                Route::GuildsIdBansUserId(guild_id),
+                Cow::from(Route::create_ban_optioned(
+                    guild_id,
+                    user_id,
+                    delete_message_days,
+                    delete_message_seconds,
+                )),
+            ),
             RouteInfo::CreateChannel { guild_id } => (
                 LightMethod::Post,
                 Route::GuildsIdChannels(guild_id),
@@ -988,11 +1121,21 @@ impl<'a> RouteInfo<'a> {
                 Route::Guilds,
                 Cow::from(Route::guilds()),
             ),
+            RouteInfo::CreateGuildFromTemplate { code } => (
+                LightMethod::Post,
+                Route::GuildsTemplatesCode,
+                Cow::from(Route::guilds_template(code)),
+            ),
             RouteInfo::CreateGuildIntegration { guild_id, integration_id } => (
                 LightMethod::Post,
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration(guild_id, integration_id)),
             ),
+            RouteInfo::CreateGuildTemplate { guild_id } => (
+                LightMethod::Post,
+                Route::GuildsIdTemplates(guild_id),
+                Cow::from(Route::guild_templates(guild_id)),
+            ),
             RouteInfo::CreateInvite { channel_id } => (
                 LightMethod::Post,
                 Route::ChannelsIdInvites(channel_id),
@@ -1053,6 +1196,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration(guild_id, integration_id)),
             ),
+            RouteInfo::DeleteGuildTemplate { code, guild_id } => (
+                LightMethod::Delete,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
             RouteInfo::DeleteInvite { code } => (
                 LightMethod::Delete,
                 Route::InvitesCode,
@@ -1145,6 +1293,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmbed(guild_id),
                 Cow::from(Route::guild_embed(guild_id)),
             ),
+            RouteInfo::EditGuildIntegration { guild_id, integration_id } => (
+                LightMethod::Patch,
+                Route::GuildsIdIntegrationsId(guild_id),
+                Cow::from(Route::guild_integration(guild_id, integration_id)),
+            ),
             RouteInfo::EditMember { guild_id, user_id } => (
                 LightMethod::Patch,
                 Route::GuildsIdMembersId(guild_id),
@@ -1216,10 +1369,15 @@ impl<'a> RouteInfo<'a> {
                     limit,
                 )),
             ),
-            RouteInfo::GetBans { guild_id } => (
+            RouteInfo::GetBan { guild_id, user_id } => (
+                LightMethod::Get,
+                Route::GuildsIdBansUserId(guild_id),
+                Cow::from(Route::guild_ban(guild_id, user_id)),
+            ),
+            RouteInfo::GetBans { after, before, guild_id, limit } => (
                 LightMethod::Get,
                 Route::GuildsIdBans(guild_id),
-                Cow::from(Route::guild_bans(guild_id)),
+                Cow::from(Route::guild_bans_optioned(guild_id, before, limit, after)),
             ),
             RouteInfo::GetBotGateway => (
                 LightMethod::Get,
@@ -1311,6 +1469,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRoles(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::GetGuildTemplates { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdTemplates(guild_id),
+                Cow::from(Route::guild_templates(guild_id)),
+            ),
             RouteInfo::GetGuildVanityUrl { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdVanityUrl(guild_id),
@@ -1411,6 +1574,7 @@ impl<'a> RouteInfo<'a> {
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook_with_token(webhook_id, token)),
             ),
+            #[allow(deprecated)]
             RouteInfo::KickMember { guild_id, user_id, reason } => (
                 LightMethod::Delete,
                 Route::GuildsIdMembersId(guild_id),
@@ -1440,6 +1604,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdBansUserId(guild_id),
                 Cow::from(Route::guild_ban(guild_id, user_id)),
             ),
+            RouteInfo::RemoveMember { guild_id, user_id } => (
+                LightMethod::Delete,
+                Route::GuildsIdMembersId(guild_id),
+                Cow::from(Route::guild_member(guild_id, user_id)),
+            ),
             RouteInfo::RemoveMemberRole { guild_id, role_id, user_id } => (
                 LightMethod::Delete,
                 Route::GuildsIdMembersIdRolesId(guild_id),
@@ -1473,6 +1642,11 @@ impl<'a> RouteInfo<'a> {
                 Route::None,
                 Cow::from(Route::status_maintenances_upcoming()),
             ),
+            RouteInfo::SyncGuildTemplate { code, guild_id } => (
+                LightMethod::Put,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
             RouteInfo::UnpinMessage { channel_id, message_id } => (
                 LightMethod::Delete,
                 Route::ChannelsIdPinsMessageId(channel_id),
@@ -125,6 +125,13 @@ impl ChannelId {
         http.as_ref().delete_channel(self.0).await
     }
 
+    /// Deletes this channel with a reason shown in the guild's audit log,
+    /// returning the channel on a successful deletion.
+    #[inline]
+    pub async fn delete_with_reason(self, http: impl AsRef<Http>, reason: &str) -> Result<Channel> {
+        http.as_ref().delete_channel_with_reason(self.0, reason).await
+    }
+
     /// Deletes a [`Message`] given its Id.
     ///
     /// Refer to [`Message::delete`] for more information.
@@ -431,6 +431,7 @@ mod test {
                     bot: false,
                     discriminator: 1,
                     name: "ab".to_string(),
+                    global_name: None,
                 },
             }
         }
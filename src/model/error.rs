@@ -72,6 +72,12 @@ pub enum Error {
     /// When attempting to delete a number of days' worth of messages that is
     /// not allowed.
     DeleteMessageDaysAmount(u8),
+    /// When attempting to delete a number of seconds' worth of messages that
+    /// is not allowed.
+    DeleteMessageSecondsAmount(u32),
+    /// When attempting to prune members inactive for a number of days that is
+    /// not allowed.
+    PruneDaysAmount(u16),
     /// Indicates that the textual content of an embed exceeds the maximum
     /// length.
     EmbedTooLarge(usize),
@@ -130,6 +136,18 @@ pub enum Error {
     NameTooShort,
     /// Indicates that the webhook name is over the 100 characters limit.
     NameTooLong,
+    /// Indicates that an emoji name is shorter than 2 characters or contains
+    /// characters other than alphanumerics and underscores.
+    ///
+    /// The invalid name is provided.
+    InvalidEmojiName(String),
+    /// Indicates that image data is not a `png`, `jpeg`, or `gif` data URI,
+    /// or that its decoded size is over the allowed limit.
+    InvalidImageData,
+    /// Indicates that a guild template code is unknown or has expired.
+    ///
+    /// The template code is provided.
+    UnknownTemplate(String),
 }
 
 impl Display for Error {
@@ -137,6 +155,8 @@ impl Display for Error {
         match self {
             Error::BulkDeleteAmount => f.write_str("Too few/many messages to bulk delete."),
             Error::DeleteMessageDaysAmount(_) => f.write_str("Invalid delete message days."),
+            Error::DeleteMessageSecondsAmount(_) => f.write_str("Invalid delete message seconds."),
+            Error::PruneDaysAmount(_) => f.write_str("Invalid prune days."),
             Error::EmbedTooLarge(_) => f.write_str("Embed too large."),
             Error::GuildNotFound => f.write_str("Guild not found in the cache."),
             Error::RoleNotFound => f.write_str("Role not found in the cache."),
@@ -149,6 +169,9 @@ impl Display for Error {
             Error::MessagingBot => f.write_str("Attempted to message another bot user."),
             Error::NameTooShort => f.write_str("Name is under the character limit."),
             Error::NameTooLong => f.write_str("Name is over the character limit."),
+            Error::InvalidEmojiName(_) => f.write_str("Invalid emoji name."),
+            Error::InvalidImageData => f.write_str("Image data has an invalid format or is too large."),
+            Error::UnknownTemplate(_) => f.write_str("Unknown or expired guild template."),
         }
     }
 }
@@ -11,7 +11,7 @@ use std::{
     collections::HashMap,
     fmt
 };
-use super::utils::deserialize_emojis;
+use super::utils::{deserialize_emojis, insert_guild_ids};
 use super::prelude::*;
 use crate::constants::OpCode;
 use crate::internal::prelude::*;
@@ -338,6 +338,16 @@ impl CacheUpdate for GuildDeleteEvent {
     type Output = Guild;
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if self.guild.unavailable {
+            // The guild is only affected by an outage, so keep it in the
+            // cache and flag it until a `GuildCreate` marks it available.
+            return cache.guilds.write().await.get_mut(&self.guild.id).map(|guild| {
+                guild.unavailable = true;
+
+                guild.clone()
+            });
+        }
+
         match cache.guilds.write().await.remove(&self.guild.id) {
             Some(guild) => {
                 for channel_id in guild.channels.keys() {
@@ -370,7 +380,7 @@ impl Serialize for GuildDeleteEvent {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[non_exhaustive]
 pub struct GuildEmojisUpdateEvent {
     #[serde(serialize_with = "serialize_emojis", deserialize_with = "deserialize_emojis")] pub emojis: HashMap<EmojiId, Emoji>,
@@ -391,6 +401,29 @@ impl CacheUpdate for GuildEmojisUpdateEvent {
     }
 }
 
+impl<'de> Deserialize<'de> for GuildEmojisUpdateEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+
+        let guild_id = map.remove("guild_id")
+            .ok_or_else(|| DeError::custom("expected guild_id"))
+            .and_then(GuildId::deserialize)
+            .map_err(DeError::custom)?;
+
+        insert_guild_ids(&mut map, "emojis", guild_id.0);
+
+        let emojis = map.remove("emojis")
+            .ok_or_else(|| DeError::custom("expected emojis"))
+            .and_then(deserialize_emojis)
+            .map_err(DeError::custom)?;
+
+        Ok(Self {
+            emojis,
+            guild_id,
+        })
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct GuildIntegrationsUpdateEvent {
@@ -417,8 +450,7 @@ impl CacheUpdate for GuildMemberAddEvent {
         }
 
         if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
-            guild.member_count += 1;
-            guild.members.insert(user_id, self.member.clone());
+            guild.insert_member(self.member.clone());
         }
 
         None
@@ -471,8 +503,7 @@ impl CacheUpdate for GuildMemberRemoveEvent {
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
-            guild.member_count -= 1;
-            return guild.members.remove(&self.user.id);
+            return guild.remove_member(self.user.id);
         }
 
         None
@@ -482,6 +513,8 @@ impl CacheUpdate for GuildMemberRemoveEvent {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct GuildMemberUpdateEvent {
+    #[serde(default)]
+    pub avatar: Option<String>,
     pub guild_id: GuildId,
     pub nick: Option<String>,
     pub roles: Vec<RoleId>,
@@ -502,6 +535,7 @@ impl CacheUpdate for GuildMemberUpdateEvent {
             let item = if let Some(member) = guild.members.get_mut(&self.user.id) {
                 let item = Some(member.clone());
 
+                member.avatar.clone_from(&self.avatar);
                 member.nick.clone_from(&self.nick);
                 member.roles.clone_from(&self.roles);
                 member.user.clone_from(&self.user);
@@ -517,6 +551,7 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                 guild.members.insert(
                     self.user.id,
                     Member {
+                        avatar: self.avatar.clone(),
                         deaf: false,
                         guild_id: self.guild_id,
                         joined_at: None,
@@ -1011,6 +1046,7 @@ impl CacheUpdate for PresenceUpdateEvent {
                 if !guild.members.contains_key(&self.presence.user_id) {
                     if let Some(user) = self.presence.user.as_ref() {
                         guild.members.insert(self.presence.user_id, Member {
+                            avatar: None,
                             deaf: false,
                             guild_id,
                             joined_at: None,
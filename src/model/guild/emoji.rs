@@ -4,7 +4,7 @@ use std::fmt::{
     Result as FmtResult,
     Write as FmtWrite
 };
-use crate::model::id::{EmojiId, RoleId};
+use crate::model::id::{EmojiId, GuildId, RoleId};
 
 #[cfg(all(feature = "cache", feature = "model"))]
 use serde_json::json;
@@ -13,8 +13,6 @@ use crate::internal::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::model::ModelError;
 #[cfg(all(feature = "cache", feature = "model"))]
-use crate::model::id::GuildId;
-#[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::http::CacheHttp;
@@ -28,6 +26,13 @@ pub struct Emoji {
     /// Whether the emoji is animated.
     #[serde(default)]
     pub animated: bool,
+    /// The Id of the guild the emoji belongs to.
+    ///
+    /// This is set for emojis received as part of a guild, a guild emoji
+    /// update, or from the emoji HTTP endpoints, and is `None` when the owning
+    /// guild is not known.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
     /// The Id of the emoji.
     pub id: EmojiId,
     /// The name of the emoji. It must be at least 2 characters long and can
@@ -89,12 +94,9 @@ impl Emoji {
     #[cfg(feature = "cache")]
     #[inline]
     pub async fn delete(&self, cache_http: impl CacheHttp) -> Result<()> {
-        let cache = cache_http.cache().ok_or(Error::Model(ModelError::ItemMissing))?;
+        let guild_id = self.owning_guild_id(&cache_http).await?;
 
-        match self.find_guild_id(&cache).await {
-            Some(guild_id) => cache_http.http().delete_emoji(guild_id.0, self.id.0).await,
-            None => Err(Error::Model(ModelError::ItemMissing)),
-        }
+        cache_http.http().delete_emoji(guild_id.0, self.id.0).await
     }
 
     /// Edits the emoji by updating it with a new name.
@@ -106,28 +108,41 @@ impl Emoji {
     /// [Manage Emojis]: crate::model::permissions::Permissions::MANAGE_EMOJIS
     #[cfg(feature = "cache")]
     pub async fn edit(&mut self, cache_http: impl CacheHttp, name: &str) -> Result<()> {
-        let cache = cache_http.cache().ok_or(Error::Model(ModelError::ItemMissing))?;
+        let guild_id = self.owning_guild_id(&cache_http).await?;
+
+        let map = json!({
+            "name": name,
+        });
 
-        match self.find_guild_id(&cache).await {
-            Some(guild_id) => {
-                let map = json!({
-                    "name": name,
-                });
+        *self = cache_http
+            .http()
+            .edit_emoji(guild_id.0, self.id.0, &map)
+            .await?;
 
-                *self = cache_http
-                    .http()
-                    .edit_emoji(guild_id.0, self.id.0, &map)
-                    .await?;
+        Ok(())
+    }
 
-                Ok(())
-            },
-            None => Err(Error::Model(ModelError::ItemMissing)),
+    /// Returns the [`guild_id`] of the emoji, falling back to searching the
+    /// cache if it is not known.
+    ///
+    /// [`guild_id`]: Self::guild_id
+    #[cfg(feature = "cache")]
+    async fn owning_guild_id(&self, cache_http: impl CacheHttp) -> Result<GuildId> {
+        if let Some(guild_id) = self.guild_id {
+            return Ok(guild_id);
         }
+
+        let cache = cache_http.cache().ok_or(Error::Model(ModelError::ItemMissing))?;
+
+        self.find_guild_id(&cache).await.ok_or(Error::Model(ModelError::ItemMissing))
     }
 
     /// Finds the [`Guild`] that owns the emoji by looking through the Cache.
     ///
+    /// Prefer the [`guild_id`] field when it is set.
+    ///
     /// [`Guild`]: super::Guild
+    /// [`guild_id`]: Self::guild_id
     ///
     /// # Examples
     ///
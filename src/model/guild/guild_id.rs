@@ -3,12 +3,14 @@ use crate::model::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(feature = "model")]
-use crate::builder::{EditGuild, EditMember, EditRole};
+use crate::builder::{EditGuild, EditIntegration, EditMember, EditRole};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
 use crate::utils;
 #[cfg(feature = "model")]
+use crate::constants::GUILD_IMAGE_MAX_SIZE;
+#[cfg(feature = "model")]
 use crate::builder::CreateChannel;
 #[cfg(feature = "model")]
 use serde_json::json;
@@ -16,13 +18,15 @@ use serde_json::json;
 use futures::stream::Stream;
 #[cfg(feature = "collector")]
 use crate::client::bridge::gateway::ShardMessenger;
+#[cfg(all(feature = "client", feature = "gateway", feature = "model"))]
+use crate::client::bridge::gateway::{ChunkGuildFilter, ShardManager};
 #[cfg(feature = "collector")]
 use crate::collector::{
     CollectReply, MessageCollectorBuilder,
     CollectReaction, ReactionCollectorBuilder,
 };
 #[cfg(feature = "model")]
-use crate::http::{Http, CacheHttp};
+use crate::http::{Http, CacheHttp, StatusCode};
 
 #[cfg(feature = "model")]
 impl GuildId {
@@ -64,6 +68,9 @@ impl GuildId {
 
     /// Ban a [`User`] from the guild with a reason. Refer to [`ban`] to further documentation.
     ///
+    /// The reason is shown in the guild's audit log, and can be at most 512
+    /// characters long.
+    ///
     /// [`ban`]: Self::ban
     #[inline]
     pub async fn ban_with_reason(
@@ -81,11 +88,71 @@ impl GuildId {
             return Err(Error::Model(ModelError::DeleteMessageDaysAmount(dmd)));
         }
 
-        if reason.len() > 512 {
+        self._ban_seconds_with_reason(http, user, u32::from(dmd) * 86_400, reason).await
+    }
+
+    /// Ban a [`User`] from the guild, deleting a number of seconds' worth of
+    /// messages (`dms`) between the range 0 and 604800 (7 days).
+    ///
+    /// This allows deleting less than a day's worth of messages, such as only
+    /// those sent in the last hour.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Examples
+    ///
+    /// Ban a member and remove all messages they've sent in the last hour:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::id::UserId;
+    /// use serenity::model::id::GuildId;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use serenity::http::Http;
+    /// # let http = Http::default();
+    /// # let user = UserId(1);
+    /// // assuming a `user` has already been bound
+    /// let _ = GuildId(81384788765712384).ban_seconds(&http, user, 3600).await;
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::DeleteMessageSecondsAmount`] if the number of
+    /// seconds' worth of messages to delete is over the maximum.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    #[inline]
+    pub async fn ban_seconds(self, http: impl AsRef<Http>, user: impl Into<UserId>, dms: u32) -> Result<()> {
+        self._ban_seconds_with_reason(http, user.into(), dms, "").await
+    }
+
+    /// Ban a [`User`] from the guild with a reason. Refer to [`ban_seconds`]
+    /// to further documentation.
+    ///
+    /// [`ban_seconds`]: Self::ban_seconds
+    #[inline]
+    pub async fn ban_seconds_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        user: impl Into<UserId>,
+        dms: u32,
+        reason: impl AsRef<str>
+    ) -> Result<()> {
+        self._ban_seconds_with_reason(http, user.into(), dms, reason.as_ref()).await
+    }
+
+    async fn _ban_seconds_with_reason(self, http: impl AsRef<Http>, user: UserId, dms: u32, reason: &str) -> Result<()> {
+        if dms > 604_800 {
+            return Err(Error::Model(ModelError::DeleteMessageSecondsAmount(dms)));
+        }
+
+        if reason.chars().count() > 512 {
             return Err(Error::ExceededLimit(reason.to_string(), 512));
         }
 
-        http.as_ref().ban_user(self.0, user.0, dmd, reason).await
+        http.as_ref().ban_user_with_seconds(self.0, user.0, dms, reason).await
     }
 
     /// Gets a list of the guild's bans.
@@ -98,6 +165,68 @@ impl GuildId {
         http.as_ref().get_bans(self.0).await
     }
 
+    /// Gets a page of the guild's bans, ordered by the banned user's Id.
+    ///
+    /// Passing `before` or `after` returns the bans of users with a lower or
+    /// higher Id respectively, while `limit` caps the page at up to 1000
+    /// bans. Prefer this over [`bans`] for guilds with many bans.
+    ///
+    /// Requires the [Ban Members] permission.
+    ///
+    /// [`bans`]: Self::bans
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    #[inline]
+    pub async fn bans_paginated(
+        self,
+        http: impl AsRef<Http>,
+        before: Option<UserId>,
+        after: Option<UserId>,
+        limit: Option<u16>,
+    ) -> Result<Vec<Ban>> {
+        http.as_ref().get_bans_paginated(self.0, before.map(|id| id.0), limit, after.map(|id| id.0)).await
+    }
+
+    /// Gets the ban of a single user, without fetching the entire ban list.
+    ///
+    /// Returns `None` if the user is not banned.
+    ///
+    /// Requires the [Ban Members] permission.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub async fn get_ban(self, http: impl AsRef<Http>, user_id: impl Into<UserId>) -> Result<Option<Ban>> {
+        match http.as_ref().get_ban(self.0, user_id.into().0).await {
+            Ok(ban) => Ok(Some(ban)),
+            Err(Error::Http(why)) if why.status_code() == Some(StatusCode::NOT_FOUND) => Ok(None),
+            Err(why) => Err(why),
+        }
+    }
+
+    /// Gets up to `limit` of the guild's most recent bans, without fetching
+    /// the entire ban list. The `limit` is capped at 1000.
+    ///
+    /// Bans are ordered by the banned user's Id, highest first. As Discord
+    /// does not expose when a ban happened, this is an approximation of the
+    /// most recent bans.
+    ///
+    /// Requires the [Ban Members] permission.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub async fn recent_bans(self, http: impl AsRef<Http>, limit: u16) -> Result<Vec<Ban>> {
+        let limit = limit.min(1000);
+
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let before = Some(i64::MAX as u64);
+        let mut bans = http.as_ref().get_bans_paginated(self.0, before, Some(limit), None).await?;
+
+        bans.sort_by(|a, b| b.user.id.cmp(&a.user.id));
+        bans.truncate(usize::from(limit));
+
+        Ok(bans)
+    }
+
     /// Gets a list of the guild's audit log entries
     #[inline]
     pub async fn audit_logs(
@@ -127,6 +256,15 @@ impl GuildId {
         Ok(channels)
     }
 
+    /// Gets all of the guild's channels over the REST API, grouped by their
+    /// kind.
+    ///
+    /// Refer to [`GuildChannels`] for how channels are grouped.
+    #[inline]
+    pub async fn channels_structured(self, http: impl AsRef<Http>) -> Result<GuildChannels> {
+        self.channels(&http).await.map(GuildChannels::from)
+    }
+
     /// Creates a [`GuildChannel`] in the the guild.
     ///
     /// Refer to [`Http::create_channel`] for more information.
@@ -172,10 +310,17 @@ impl GuildId {
     /// how to read an image from the filesystem and encode it as base64. Most
     /// of the example can be applied similarly for this method.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidEmojiName`] if the name is shorter than 2
+    /// characters or contains characters other than alphanumerics and
+    /// underscores.
+    ///
     /// [`EditProfile::avatar`]: crate::builder::EditProfile::avatar
     /// [Manage Emojis]: Permissions::MANAGE_EMOJIS
-    #[inline]
     pub async fn create_emoji(self, http: impl AsRef<Http>, name: &str, image: &str) -> Result<Emoji> {
+        check_emoji_name(name)?;
+
         let map = json!({
             "name": name,
             "image": image,
@@ -204,8 +349,13 @@ impl GuildId {
     ///
     /// See the documentation for [`Guild::create_role`] on how to use this.
     ///
+    /// If a [`position`] was set, the returned role reflects the position it
+    /// was moved to.
+    ///
     /// **Note**: Requires the [Manage Roles] permission.
     ///
+    /// [`position`]: EditRole::position
+    ///
     /// [Manage Roles]: Permissions::MANAGE_ROLES
     #[inline]
     pub async fn create_role<F>(self, http: impl AsRef<Http>, f: F) -> Result<Role>
@@ -214,15 +364,41 @@ impl GuildId {
         f(&mut edit_role);
         let map = utils::hashmap_to_json_map(edit_role.0);
 
-        let role = http.as_ref().create_role(self.0, &map).await?;
+        let mut role = http.as_ref().create_role(self.0, &map).await?;
 
         if let Some(position) = map.get("position").and_then(Value::as_u64) {
-            self.edit_role_position(&http, role.id, position).await?;
+            let roles = self.edit_role_position(&http, role.id, position).await?;
+
+            if let Some(moved) = roles.into_iter().find(|r| r.id == role.id) {
+                role = moved;
+            }
         }
 
         Ok(role)
     }
 
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// The `name` must be between 1 and 100 characters long, and the
+    /// `description` at most 120 characters.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn create_template(
+        self,
+        http: impl AsRef<Http>,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        let map = json!({
+            "name": name,
+            "description": description,
+        });
+
+        http.as_ref().create_guild_template(self.0, &map).await
+    }
+
     /// Deletes the current guild if the current account is the owner of the
     /// guild.
     ///
@@ -267,6 +443,31 @@ impl GuildId {
         http.as_ref().delete_role(self.0, role_id.into().0).await
     }
 
+    /// Deletes a [`Role`] by Id from the guild with a reason shown in the
+    /// guild's audit log. Refer to [`delete_role`] for further documentation.
+    ///
+    /// [`delete_role`]: Self::delete_role
+    #[inline]
+    pub async fn delete_role_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        reason: &str,
+    ) -> Result<()> {
+        http.as_ref().delete_role_with_reason(self.0, role_id.into().0, reason).await
+    }
+
+    /// Deletes a [`GuildTemplate`] by its code, returning the deleted
+    /// template.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete_template(self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        http.as_ref().delete_guild_template(self.0, code).await
+    }
+
     /// Edits the current guild with new data where specified.
     ///
     /// Refer to [`Guild::edit`] for more information.
@@ -274,6 +475,11 @@ impl GuildId {
     /// **Note**: Requires the current user to have the [Manage Guild]
     /// permission.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageData`] if a new icon, banner, or
+    /// splash is not a supported image or is too large.
+    ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     #[inline]
     pub async fn edit<F>(&mut self, http: impl AsRef<Http>, f: F) -> Result<PartialGuild>
@@ -281,6 +487,13 @@ impl GuildId {
     {
         let mut edit_guild = EditGuild::default();
         f(&mut edit_guild);
+
+        for key in &["banner", "icon", "splash"] {
+            if let Some(Value::String(image)) = edit_guild.0.get(key) {
+                utils::validate_image(image, GUILD_IMAGE_MAX_SIZE)?;
+            }
+        }
+
         let map = utils::hashmap_to_json_map(edit_guild.0);
 
         http.as_ref().edit_guild(self.0, &map).await
@@ -326,6 +539,29 @@ impl GuildId {
         http.as_ref().edit_member(self.0, user_id.into().0, &map).await
     }
 
+    /// Edits the settings of an integration of the guild.
+    ///
+    /// Refer to [`EditIntegration`] for the settings that can be changed.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn edit_integration<F>(
+        self,
+        http: impl AsRef<Http>,
+        integration_id: impl Into<IntegrationId>,
+        f: F,
+    ) -> Result<()>
+    where F: FnOnce(&mut EditIntegration) -> &mut EditIntegration
+    {
+        let mut edit_integration = EditIntegration::default();
+        f(&mut edit_integration);
+        let map = utils::hashmap_to_json_map(edit_integration.0);
+
+        http.as_ref().edit_guild_integration(self.0, integration_id.into().0, &map).await
+    }
+
     /// Edits the current user's nickname for the guild.
     ///
     /// Pass `None` to reset the nickname.
@@ -479,6 +715,32 @@ impl GuildId {
         http.as_ref().get_guild_members(self.0, limit, after.into().map(|x| x.0)).await
     }
 
+    /// Gets all of the guild's members.
+    ///
+    /// This makes repeated calls to [`members`] with the maximum limit of
+    /// 1000, until a page with fewer members is returned. Use
+    /// [`members_iter`] to process members as they arrive instead.
+    ///
+    /// [`members`]: Self::members
+    /// [`members_iter`]: Self::members_iter
+    pub async fn all_members(self, http: impl AsRef<Http>) -> Result<Vec<Member>> {
+        let http = http.as_ref();
+        let mut members = Vec::new();
+        let mut after = None;
+
+        loop {
+            let page = self.members(http, Some(1000), after).await?;
+            let exhausted = page.len() < 1000;
+
+            after = page.last().map(|member| member.user.id);
+            members.extend(page);
+
+            if exhausted {
+                return Ok(members);
+            }
+        }
+    }
+
     /// Streams over all the members in a guild.
     ///
     /// This is accomplished and equivilent to repeated calls to [`members`].
@@ -516,6 +778,38 @@ impl GuildId {
         MembersIter::<H>::stream(http, self)
     }
 
+    /// Requests the guild's members from the gateway, which are then received
+    /// in chunks through the [`EventHandler::guild_members_chunk`] event and
+    /// added to the cache.
+    ///
+    /// Pass a `query` to only request members whose username starts with it,
+    /// and a `limit` to cap the number of members returned.
+    ///
+    /// The request is routed through the shard the guild is on, as found by
+    /// [`ShardManager::guild_messenger`]. Returns `false` if that shard is not
+    /// managed by the given `shard_manager`, in which case no request is
+    /// sent. Requesting all members requires the
+    /// [`GatewayIntents::GUILD_MEMBERS`] intent.
+    ///
+    /// [`EventHandler::guild_members_chunk`]: crate::client::EventHandler::guild_members_chunk
+    /// [`GatewayIntents::GUILD_MEMBERS`]: crate::client::bridge::gateway::GatewayIntents::GUILD_MEMBERS
+    #[cfg(all(feature = "client", feature = "gateway"))]
+    pub async fn chunk_members(self, shard_manager: &ShardManager, query: Option<&str>, limit: Option<u16>) -> bool {
+        let shard_messenger = match shard_manager.guild_messenger(self).await {
+            Some(shard_messenger) => shard_messenger,
+            None => return false,
+        };
+
+        let filter = match query {
+            Some(query) => ChunkGuildFilter::Query(query.to_string()),
+            None => ChunkGuildFilter::None,
+        };
+
+        shard_messenger.chunk_guild(self, limit, filter, None);
+
+        true
+    }
+
     /// Moves a member to a specific voice channel.
     ///
     /// Requires the [Move Members] permission.
@@ -564,9 +858,16 @@ impl GuildId {
     ///
     /// Requires the [Kick Members] permission.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::PruneDaysAmount`] if the number of days is not
+    /// between 1 and 30.
+    ///
     /// [Kick Members]: Permissions::KICK_MEMBERS
     #[inline]
     pub async fn prune_count(self, http: impl AsRef<Http>, days: u16) -> Result<GuildPrune> {
+        check_prune_days(days)?;
+
         let map = json!({
             "days": days,
         });
@@ -653,9 +954,16 @@ impl GuildId {
     ///
     /// **Note**: Requires the [Kick Members] permission.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::PruneDaysAmount`] if the number of days is not
+    /// between 1 and 30.
+    ///
     /// [Kick Members]: Permissions::KICK_MEMBERS
     #[inline]
     pub async fn start_prune(self, http: impl AsRef<Http>, days: u16) -> Result<GuildPrune> {
+        check_prune_days(days)?;
+
         let map = json!({
             "days": days,
         });
@@ -663,6 +971,26 @@ impl GuildId {
         http.as_ref().start_guild_prune(self.0, &map).await
     }
 
+    /// Syncs a [`GuildTemplate`] with the current state of the guild.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn sync_template(self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        http.as_ref().sync_guild_template(self.0, code).await
+    }
+
+    /// Gets the guild's [`GuildTemplate`]s.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn templates(self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        http.as_ref().get_guild_templates(self.0).await
+    }
+
     /// Unbans a [`User`] from the guild.
     ///
     /// Requires the [Ban Members] permission.
@@ -673,14 +1001,41 @@ impl GuildId {
         http.as_ref().remove_ban(self.0, user_id.into().0).await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Unbans a [`User`] from the guild with a reason. Refer to [`unban`] for
+    /// further documentation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ExceededLimit`] if the reason is longer than 512
+    /// characters.
+    ///
+    /// [`unban`]: Self::unban
+    pub async fn unban_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        let reason = reason.as_ref();
+
+        if reason.chars().count() > 512 {
+            return Err(Error::ExceededLimit(reason.to_string(), 512));
+        }
+
+        http.as_ref().remove_ban_with_reason(self.0, user_id.into().0, reason).await
+    }
+
+    /// Retrieve's the guild's vanity URL code, or `None` if the guild does
+    /// not have one.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
-    #[inline]
-    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<String> {
-        http.as_ref().get_guild_vanity_url(self.0).await
+    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<Option<String>> {
+        match http.as_ref().get_guild_vanity_url(self.0).await {
+            Err(Error::Http(why)) if why.status_code() == Some(StatusCode::NOT_FOUND) => Ok(None),
+            result => result,
+        }
     }
 
     /// Retrieves the guild's webhooks.
@@ -718,6 +1073,27 @@ impl GuildId {
     }
 }
 
+#[cfg(feature = "model")]
+pub(super) fn check_prune_days(days: u16) -> Result<()> {
+    if days == 0 || days > 30 {
+        return Err(Error::Model(ModelError::PruneDaysAmount(days)));
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "model")]
+pub(super) fn check_emoji_name(name: &str) -> Result<()> {
+    let valid = name.chars().count() >= 2
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if !valid {
+        return Err(Error::Model(ModelError::InvalidEmojiName(name.to_string())));
+    }
+
+    Ok(())
+}
+
 impl From<PartialGuild> for GuildId {
     /// Gets the Id of a partial guild.
     fn from(guild: PartialGuild) -> GuildId { guild.id }
@@ -5,6 +5,12 @@ use super::*;
 #[non_exhaustive]
 pub struct Integration {
     pub id: IntegrationId,
+    /// The Id of the guild the integration belongs to.
+    ///
+    /// This is only known for integrations retrieved through
+    /// [`GuildId::integrations`] and related methods.
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
     pub account: IntegrationAccount,
     pub enabled: bool,
     #[serde(rename = "expire_behaviour")] pub expire_behaviour: u64,
@@ -17,6 +23,67 @@ pub struct Integration {
     pub user: User,
 }
 
+#[cfg(feature = "model")]
+impl Integration {
+    /// Deletes the integration from its guild.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::ItemMissing`] if the [`guild_id`] is not known.
+    ///
+    /// [`guild_id`]: Self::guild_id
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<()> {
+        self.owning_guild_id()?.delete_integration(http, self.id).await
+    }
+
+    /// Edits the settings of the integration.
+    ///
+    /// Refer to [`EditIntegration`] for the settings that can be changed.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::ItemMissing`] if the [`guild_id`] is not known.
+    ///
+    /// [`guild_id`]: Self::guild_id
+    /// [`EditIntegration`]: crate::builder::EditIntegration
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn edit<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where F: FnOnce(&mut EditIntegration) -> &mut EditIntegration
+    {
+        self.owning_guild_id()?.edit_integration(http, self.id, f).await
+    }
+
+    /// Starts a sync of the integration.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::ItemMissing`] if the [`guild_id`] is not known.
+    ///
+    /// [`guild_id`]: Self::guild_id
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn sync(&self, http: impl AsRef<Http>) -> Result<()> {
+        self.owning_guild_id()?.start_integration_sync(http, self.id).await
+    }
+
+    /// Returns the [`guild_id`] of the integration, or a
+    /// [`ModelError::ItemMissing`] if it is not known.
+    ///
+    /// [`guild_id`]: Self::guild_id
+    fn owning_guild_id(&self) -> Result<GuildId> {
+        self.guild_id.ok_or(Error::Model(ModelError::ItemMissing))
+    }
+}
+
 impl From<Integration> for IntegrationId {
     /// Gets the Id of integration.
     fn from(integration: Integration) -> IntegrationId { integration.id }
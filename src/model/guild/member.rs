@@ -11,8 +11,6 @@ use std::fmt::{
 use crate::builder::EditMember;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::internal::prelude::*;
-#[cfg(feature = "model")]
-use std::borrow::Cow;
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 use crate::utils::Colour;
 #[cfg(all(feature = "cache"))]
@@ -26,6 +24,9 @@ use crate::http::{Http, CacheHttp};
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Member {
+    /// The member's guild-specific avatar hash, if they have set one.
+    #[serde(default)]
+    pub avatar: Option<String>,
     /// Indicator of whether the member can hear in voice channels.
     pub deaf: bool,
     /// The unique Id of the guild that the member is a part of.
@@ -157,12 +158,20 @@ impl Member {
 
     /// Calculates the member's display name.
     ///
-    /// The nickname takes priority over the member's username if it exists.
+    /// The nickname takes priority if it exists, followed by the user's
+    /// [`global_name`], and lastly their username.
+    ///
+    /// This is the name that should be used wherever a member is shown to
+    /// humans.
+    ///
+    /// [`global_name`]: User::global_name
     #[inline]
-    pub fn display_name(&self) -> Cow<'_, String> {
+    pub fn display_name(&self) -> String {
         self.nick
             .as_ref()
-            .map_or_else(|| Cow::Owned(self.user.name.clone()), Cow::Borrowed)
+            .or_else(|| self.user.global_name.as_ref())
+            .unwrap_or(&self.user.name)
+            .clone()
     }
 
     /// Returns the DiscordTag of a Member, taking possible nickname into account.
@@ -229,6 +238,18 @@ impl Member {
         highest
     }
 
+    /// Retrieves the ID of the member's highest role in the hierarchy, if they
+    /// have one.
+    ///
+    /// Refer to [`highest_role_info`] for when this may return `None`.
+    ///
+    /// [`highest_role_info`]: Self::highest_role_info
+    #[cfg(feature = "cache")]
+    #[inline]
+    pub async fn highest_role(&self, cache: impl AsRef<Cache>) -> Option<RoleId> {
+        self.highest_role_info(cache).await.map(|(id, _)| id)
+    }
+
     /// Kick the member from the guild.
     ///
     /// **Note**: Requires the [Kick Members] permission.
@@ -470,6 +491,39 @@ impl Display for Member {
     }
 }
 
+/// The kind of [`Member`] to consider when looking up members by name.
+///
+/// Refer to [`Guild::member_named_of_kind`] for an example of its use.
+///
+/// [`Guild::member_named_of_kind`]: crate::model::guild::Guild::member_named_of_kind
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum MemberKind {
+    /// Any member, whether human or bot.
+    Any,
+    /// Only members that are not bots.
+    Human,
+    /// Only members that are bots.
+    Bot,
+}
+
+impl MemberKind {
+    /// Whether the given member is of this kind.
+    pub fn matches(self, member: &Member) -> bool {
+        match self {
+            MemberKind::Any => true,
+            MemberKind::Human => !member.user.bot,
+            MemberKind::Bot => member.user.bot,
+        }
+    }
+}
+
+impl Default for MemberKind {
+    fn default() -> Self {
+        MemberKind::Any
+    }
+}
+
 /// A partial amount of data for a member.
 ///
 /// This is used in [`Message`]s from [`Guild`]s.
@@ -8,6 +8,7 @@ mod partial_guild;
 mod role;
 mod audit_log;
 mod premium_tier;
+mod template;
 
 pub use self::emoji::*;
 pub use self::guild_id::*;
@@ -17,6 +18,7 @@ pub use self::partial_guild::*;
 pub use self::role::*;
 pub use self::audit_log::*;
 pub use self::premium_tier::*;
+pub use self::template::*;
 
 use chrono::{DateTime, Utc};
 use crate::model::prelude::*;
@@ -29,13 +31,21 @@ use crate::cache::Cache;
 #[cfg(all(feature = "http", feature = "model"))]
 use serde_json::json;
 #[cfg(feature = "model")]
-use crate::builder::{CreateChannel, EditGuild, EditMember, EditRole};
+use crate::builder::{CreateChannel, EditGuild, EditIntegration, EditMember, EditRole};
 #[cfg(feature = "model")]
-use crate::constants::LARGE_THRESHOLD;
+use crate::constants::{GUILD_IMAGE_MAX_SIZE, LARGE_THRESHOLD};
+#[cfg(feature = "model")]
+use crate::utils;
+#[cfg(feature = "model")]
+use std::path::Path;
 #[cfg(feature = "model")]
 use tracing::{error, warn};
 #[cfg(feature = "model")]
-use crate::http::{Http, CacheHttp};
+use crate::http::{Http, CacheHttp, StatusCode};
+#[cfg(all(feature = "cache", feature = "model"))]
+use std::time::Duration;
+#[cfg(all(feature = "client", feature = "gateway", feature = "model"))]
+use crate::client::bridge::gateway::ShardManager;
 
 /// A representation of a banning of a user.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Hash, Serialize)]
@@ -105,10 +115,19 @@ pub struct Guild {
     /// that of the default channel (typically `#general`).
     pub id: GuildId,
     /// The date that the current user joined the guild.
-    pub joined_at: DateTime<Utc>,
+    ///
+    /// This may be missing when a guild becomes available again after an
+    /// outage.
+    pub joined_at: Option<DateTime<Utc>>,
     /// Indicator of whether the guild is considered "large" by Discord.
     pub large: bool,
     /// The number of members in the guild.
+    ///
+    /// This is set when the guild is received and kept up to date as members
+    /// join and leave, but is only guaranteed to be exact for small guilds
+    /// whose member list is fully cached. See [`resync_member_count`].
+    ///
+    /// [`resync_member_count`]: Self::resync_member_count
     pub member_count: u64,
     /// Users who are members of the guild.
     ///
@@ -141,6 +160,11 @@ pub struct Guild {
     pub splash: Option<String>,
     /// The ID of the channel to which system messages are sent.
     pub system_channel_id: Option<ChannelId>,
+    /// Indicator of whether the guild is unavailable due to an outage.
+    ///
+    /// The cache keeps a guild that becomes unavailable, with this set, until
+    /// it is available again.
+    pub unavailable: bool,
     /// Indicator of the current verification level of the guild.
     pub verification_level: VerificationLevel,
     /// A mapping of [`User`]s to their current voice state.
@@ -179,16 +203,14 @@ impl Guild {
     }
 
     /// Returns the "default" channel of the guild for the passed user id.
-    /// (This returns the first channel that can be read by the user, if there isn't one,
-    /// returns `None`)
+    /// (This returns the topmost text channel that can be read by the user, if
+    /// there isn't one, returns `None`)
     pub async fn default_channel(&self, uid: UserId) -> Option<&GuildChannel> {
-        for (cid, channel) in &self.channels {
-            if self.user_permissions_in(*cid, uid).read_messages() {
-                return Some(channel);
-            }
-        }
-
-        None
+        self.channels
+            .values()
+            .filter(|channel| channel.kind == ChannelType::Text)
+            .filter(|channel| self.user_permissions_in(channel.id, uid).read_messages())
+            .min_by_key(|channel| (channel.position, channel.id))
     }
 
     /// Returns the guaranteed "default" channel of the guild.
@@ -275,6 +297,9 @@ impl Guild {
 
     /// Ban a [`User`] from the guild with a reason. Refer to [`ban`] to further documentation.
     ///
+    /// The reason is shown in the guild's audit log, and can be at most 512
+    /// characters long.
+    ///
     /// [`ban`]: Self::ban
     #[inline]
     pub async fn ban_with_reason(
@@ -303,6 +328,125 @@ impl Guild {
         self.id.ban_with_reason(cache_http.http(), user, dmd, reason).await
     }
 
+    /// Bans each of the given [`User`]s from the guild, deleting the given
+    /// number of days' worth of their messages (`dmd`).
+    ///
+    /// Every user is banned in turn and the result for each is returned
+    /// alongside its Id, so that a single failure does not abort the rest.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::DeleteMessageDaysAmount`] if the number of
+    /// days' worth of messages to delete is over the maximum.
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to perform bans.
+    ///
+    /// Both are checked once, before any user is banned.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub async fn ban_multiple<U>(
+        &self,
+        cache_http: impl CacheHttp,
+        users: &[U],
+        dmd: u8,
+    ) -> Result<Vec<(UserId, Result<()>)>>
+    where U: Into<UserId> + Copy
+    {
+        if dmd > 7 {
+            return Err(Error::Model(ModelError::DeleteMessageDaysAmount(dmd)));
+        }
+
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::BAN_MEMBERS;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        let mut results = Vec::with_capacity(users.len());
+
+        for &user in users {
+            let user_id = user.into();
+
+            #[cfg(feature = "cache")]
+            {
+                if let Some(cache) = cache_http.cache() {
+                    if let Err(why) = self.check_hierarchy(cache, user_id).await {
+                        results.push((user_id, Err(why)));
+
+                        continue;
+                    }
+                }
+            }
+
+            let result = self.id.ban(cache_http.http(), user_id, dmd).await;
+
+            results.push((user_id, result));
+        }
+
+        Ok(results)
+    }
+
+    /// Ban a [`User`] from the guild, deleting a number of seconds' worth of
+    /// messages (`dms`) between the range 0 and 604800 (7 days).
+    ///
+    /// Refer to the documentation for [`GuildId::ban_seconds`] for more
+    /// information.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidPermissions`] if the current user does
+    /// not have permission to perform bans.
+    ///
+    /// Returns a [`ModelError::DeleteMessageSecondsAmount`] if the number of
+    /// seconds' worth of messages to delete is over the maximum.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    #[inline]
+    pub async fn ban_seconds(&self, cache_http: impl CacheHttp, user: impl Into<UserId>, dms: u32) -> Result<()> {
+        self._ban_seconds_with_reason(cache_http, user.into(), dms, "").await
+    }
+
+    /// Ban a [`User`] from the guild with a reason. Refer to [`ban_seconds`]
+    /// to further documentation.
+    ///
+    /// [`ban_seconds`]: Self::ban_seconds
+    #[inline]
+    pub async fn ban_seconds_with_reason(
+        &self,
+        cache_http: impl CacheHttp,
+        user: impl Into<UserId>,
+        dms: u32,
+        reason: impl AsRef<str>) -> Result<()> {
+        self._ban_seconds_with_reason(cache_http, user.into(), dms, reason.as_ref()).await
+    }
+
+    async fn _ban_seconds_with_reason(&self, cache_http: impl CacheHttp, user: UserId, dms: u32, reason: &str) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                let req = Permissions::BAN_MEMBERS;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+
+                self.check_hierarchy(cache, user).await?;
+            }
+        }
+
+        self.id.ban_seconds_with_reason(cache_http.http(), user, dms, reason).await
+    }
+
     /// Retrieves a list of [`Ban`]s for the guild.
     ///
     /// **Note**: Requires the [Ban Members] permission.
@@ -328,17 +472,120 @@ impl Guild {
         self.id.bans(cache_http.http()).await
     }
 
+    /// Retrieves a page of the guild's [`Ban`]s. Refer to
+    /// [`GuildId::bans_paginated`] for how the page is selected.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to perform bans.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub async fn bans_paginated(
+        &self,
+        cache_http: impl CacheHttp,
+        before: Option<UserId>,
+        after: Option<UserId>,
+        limit: Option<u16>,
+    ) -> Result<Vec<Ban>> {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::BAN_MEMBERS;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.bans_paginated(cache_http.http(), before, after, limit).await
+    }
+
+    /// Retrieves the [`Ban`] of a single user, returning `None` if the user
+    /// is not banned.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to perform bans.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub async fn get_ban(&self, cache_http: impl CacheHttp, user_id: impl Into<UserId>) -> Result<Option<Ban>> {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::BAN_MEMBERS;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.get_ban(cache_http.http(), user_id).await
+    }
+
+    /// Retrieves up to `limit` of the guild's most recent [`Ban`]s, capped at
+    /// 1000. Refer to [`GuildId::recent_bans`] for how bans are ordered.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to perform bans.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub async fn recent_bans(&self, cache_http: impl CacheHttp, limit: u16) -> Result<Vec<Ban>> {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::BAN_MEMBERS;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.recent_bans(cache_http.http(), limit).await
+    }
+
     /// Retrieves a list of [`AuditLogs`] for the guild.
-    #[inline]
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to view the audit log.
+    ///
+    /// [View Audit Log]: Permissions::VIEW_AUDIT_LOG
     pub async fn audit_logs(
         &self,
-        http: impl AsRef<Http>,
+        cache_http: impl CacheHttp,
         action_type: Option<u8>,
         user_id: Option<UserId>,
         before: Option<AuditLogEntryId>,
         limit: Option<u8>
     ) -> Result<AuditLogs> {
-        self.id.audit_logs(&http, action_type, user_id, before, limit).await
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::VIEW_AUDIT_LOG;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.audit_logs(cache_http.http(), action_type, user_id, before, limit).await
     }
 
     /// Gets all of the guild's channels over the REST API.
@@ -347,6 +594,15 @@ impl Guild {
         self.id.channels(&http).await
     }
 
+    /// Gets all of the guild's channels over the REST API, grouped by their
+    /// kind.
+    ///
+    /// Refer to [`GuildChannels`] for how channels are grouped.
+    #[inline]
+    pub async fn channels_structured(&self, http: impl AsRef<Http>) -> Result<GuildChannels> {
+        self.id.channels_structured(&http).await
+    }
+
     /// Creates a guild with the data provided.
     ///
     /// Only a [`PartialGuild`] will be immediately returned, and a full
@@ -367,10 +623,19 @@ impl Guild {
     /// let _guild = Guild::create_guild(&http, "test", Region::UsWest, None).await;
     /// ```
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageData`] if the `icon` is not a
+    /// supported image or is too large.
+    ///
     /// [`Shard`]: crate::gateway::Shard
     /// [US West region]: Region::UsWest
     /// [whitelist]: https://discord.com/developers/docs/resources/guild#create-guild
     pub async fn create(http: impl AsRef<Http>, name: &str, region: Region, icon: Option<&str>) -> Result<PartialGuild> {
+        if let Some(icon) = icon {
+            utils::validate_image(icon, GUILD_IMAGE_MAX_SIZE)?;
+        }
+
         let map = json!({
             "icon": icon,
             "name": name,
@@ -380,6 +645,48 @@ impl Guild {
         http.as_ref().create_guild(&map).await
     }
 
+    /// Creates a guild from the guild template with the given `code`.
+    ///
+    /// The channels, roles and settings of the new guild are copied from the
+    /// template. As with [`create`], only a [`PartialGuild`] is returned, and
+    /// a full [`Guild`] will be received over a [`Shard`].
+    ///
+    /// **Note**: Bots can only use this when they are in fewer than 10
+    /// guilds.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::UnknownTemplate`] if no template with the given
+    /// `code` exists, or if it has expired.
+    ///
+    /// Returns a [`ModelError::InvalidImageData`] if the `icon` is not a
+    /// supported image or is too large.
+    ///
+    /// [`create`]: Self::create
+    /// [`Shard`]: crate::gateway::Shard
+    pub async fn create_from_template(
+        http: impl AsRef<Http>,
+        code: &str,
+        name: &str,
+        icon: Option<&str>,
+    ) -> Result<PartialGuild> {
+        if let Some(icon) = icon {
+            utils::validate_image(icon, GUILD_IMAGE_MAX_SIZE)?;
+        }
+
+        let map = json!({
+            "icon": icon,
+            "name": name,
+        });
+
+        match http.as_ref().create_guild_from_template(code, &map).await {
+            Err(Error::Http(why)) if why.status_code() == Some(StatusCode::NOT_FOUND) => {
+                Err(Error::Model(ModelError::UnknownTemplate(code.to_string())))
+            },
+            other => other,
+        }
+    }
+
     /// Creates a new [`Channel`] in the guild.
     ///
     /// **Note**: Requires the [Manage Channels] permission.
@@ -396,11 +703,27 @@ impl Guild {
     ///     .await;
     /// ```
     ///
+    /// Create a channel directly inside of a category, by setting its
+    /// [`category`]:
+    ///
+    /// ```rust,ignore
+    /// use serenity::model::{ChannelId, ChannelType};
+    ///
+    /// // assuming a `guild` has already been bound
+    ///
+    /// let _ = guild
+    ///     .create_channel(&http, |c| {
+    ///         c.name("my-test-channel").kind(ChannelType::Text).category(ChannelId(7))
+    ///     })
+    ///     .await;
+    /// ```
+    ///
     /// # Errors
     ///
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
     /// if the current user does not have permission to perform bans.
     ///
+    /// [`category`]: CreateChannel::category
     /// [Manage Channels]: Permissions::MANAGE_CHANNELS
     pub async fn create_channel(&self, cache_http: impl CacheHttp, f: impl FnOnce(&mut CreateChannel) -> &mut CreateChannel) -> Result<GuildChannel> {
         #[cfg(feature = "cache")]
@@ -441,6 +764,32 @@ impl Guild {
         self.id.create_emoji(&http, name, image).await
     }
 
+    /// Creates an emoji in the guild with a name and an image read from the
+    /// file at `path`.
+    ///
+    /// The image is encoded with [`utils::read_image`]. Refer to
+    /// [`create_emoji`] for the requirements on the name.
+    ///
+    /// Requires the [Manage Emojis] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Io`] if the file could not be read.
+    ///
+    /// [`create_emoji`]: Self::create_emoji
+    /// [`utils::read_image`]: crate::utils::read_image
+    /// [Manage Emojis]: Permissions::MANAGE_EMOJIS
+    pub async fn create_emoji_from_path(
+        &self,
+        http: impl AsRef<Http>,
+        name: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<Emoji> {
+        let image = utils::read_image(path)?;
+
+        self.create_emoji(http, name, &image).await
+    }
+
     /// Creates an integration for the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -453,6 +802,14 @@ impl Guild {
 
     /// Creates a new role in the guild with the data set, if any.
     ///
+    /// New roles are placed at position 1, directly above `@everyone`,
+    /// without changing the positions of existing roles. To place the role
+    /// elsewhere, set its [`position`] or use [`edit_role_position`]
+    /// afterwards. The returned role reflects the position it ended up at.
+    ///
+    /// If the `cache` is enabled, the new role is also added to the cached
+    /// guild.
+    ///
     /// **Note**: Requires the [Manage Roles] permission.
     ///
     /// # Examples
@@ -470,6 +827,8 @@ impl Guild {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
     /// if the current user does not have permission to perform bans.
     ///
+    /// [`position`]: EditRole::position
+    /// [`edit_role_position`]: Self::edit_role_position
     /// [Manage Roles]: Permissions::MANAGE_ROLES
     pub async fn create_role<F>(&self, cache_http: impl CacheHttp, f: F) -> Result<Role>
     where F: FnOnce(&mut EditRole) -> &mut EditRole
@@ -485,34 +844,111 @@ impl Guild {
             }
         }
 
-        self.id.create_role(cache_http.http(), f).await
-    }
+        let role = self.id.create_role(cache_http.http(), f).await?;
 
-    /// Deletes the current guild if the current user is the owner of the
-    /// guild.
-    ///
-    /// **Note**: Requires the current user to be the owner of the guild.
-    ///
-    /// # Errors
-    ///
-    /// If the `cache` is enabled, then returns a [`ModelError::InvalidUser`]
-    /// if the current user is not the guild owner.
-    pub async fn delete(&self, cache_http: impl CacheHttp) -> Result<PartialGuild> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                if self.owner_id != cache.current_user().await.id {
-                    let req = Permissions::MANAGE_GUILD;
-
-                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                if let Some(guild) = cache.guilds.write().await.get_mut(&self.id) {
+                    guild.roles.insert(role.id, role.clone());
                 }
             }
         }
 
-        self.id.delete(cache_http.http()).await
+        Ok(role)
     }
 
-    /// Deletes an [`Emoji`] from the guild.
+    /// Creates a new [`Role`] with the given properties, without a builder
+    /// closure.
+    ///
+    /// Refer to [`create_role`] to set any other properties of the role.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to manage roles.
+    ///
+    /// [`create_role`]: Self::create_role
+    /// [Manage Roles]: Permissions::MANAGE_ROLES
+    #[inline]
+    pub async fn create_role_with(
+        &self,
+        cache_http: impl CacheHttp,
+        name: &str,
+        permissions: Permissions,
+        colour: u64,
+        hoist: bool,
+        mentionable: bool,
+    ) -> Result<Role> {
+        self.create_role(cache_http, |r| r
+            .name(name)
+            .permissions(permissions)
+            .colour(colour)
+            .hoist(hoist)
+            .mentionable(mentionable)
+        ).await
+    }
+
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// Refer to [`GuildId::create_template`] for the restrictions on the
+    /// `name` and `description`.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn create_template(
+        &self,
+        cache_http: impl CacheHttp,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::MANAGE_GUILD;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.create_template(cache_http.http(), name, description).await
+    }
+
+    /// Deletes the current guild if the current user is the owner of the
+    /// guild.
+    ///
+    /// **Note**: Requires the current user to be the owner of the guild.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, then returns a [`ModelError::InvalidUser`]
+    /// if the current user is not the guild owner.
+    pub async fn delete(&self, cache_http: impl CacheHttp) -> Result<PartialGuild> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if self.owner_id != cache.current_user().await.id {
+                    let req = Permissions::MANAGE_GUILD;
+
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.delete(cache_http.http()).await
+    }
+
+    /// Deletes an [`Emoji`] from the guild.
     ///
     /// Requires the [Manage Emojis] permission.
     ///
@@ -545,6 +981,46 @@ impl Guild {
         self.id.delete_role(&http, role_id).await
     }
 
+    /// Deletes a [`Role`] by Id from the guild with a reason shown in the
+    /// guild's audit log. Refer to [`delete_role`] for further documentation.
+    ///
+    /// [`delete_role`]: Self::delete_role
+    #[inline]
+    pub async fn delete_role_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        reason: &str,
+    ) -> Result<()> {
+        self.id.delete_role_with_reason(&http, role_id, reason).await
+    }
+
+    /// Deletes a [`GuildTemplate`] by its code, returning the deleted
+    /// template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn delete_template(&self, cache_http: impl CacheHttp, code: &str) -> Result<GuildTemplate> {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::MANAGE_GUILD;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.delete_template(cache_http.http(), code).await
+    }
+
     /// Edits the current guild with new data where specified.
     ///
     /// Refer to `EditGuild`'s documentation for a full list of methods.
@@ -588,19 +1064,7 @@ impl Guild {
 
         match self.id.edit(cache_http.http(), f).await {
             Ok(guild) => {
-                self.afk_channel_id = guild.afk_channel_id;
-                self.afk_timeout = guild.afk_timeout;
-                self.default_message_notifications = guild.default_message_notifications;
-                self.emojis = guild.emojis;
-                self.features = guild.features;
-                self.icon = guild.icon;
-                self.mfa_level = guild.mfa_level;
-                self.name = guild.name;
-                self.owner_id = guild.owner_id;
-                self.region = guild.region;
-                self.roles = guild.roles;
-                self.splash = guild.splash;
-                self.verification_level = guild.verification_level;
+                self.merge_partial(guild);
 
                 Ok(())
             },
@@ -641,6 +1105,25 @@ impl Guild {
         self.id.edit_member(&http, user_id, f).await
     }
 
+    /// Edits the settings of an integration of the guild.
+    ///
+    /// Refer to [`EditIntegration`] for the settings that can be changed.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn edit_integration<F>(
+        &self,
+        http: impl AsRef<Http>,
+        integration_id: impl Into<IntegrationId>,
+        f: F,
+    ) -> Result<()>
+    where F: FnOnce(&mut EditIntegration) -> &mut EditIntegration
+    {
+        self.id.edit_integration(&http, integration_id, f).await
+    }
+
     /// Edits the current user's nickname for the guild.
     ///
     /// Pass `None` to reset the nickname.
@@ -800,10 +1283,55 @@ impl Guild {
     }
 
     /// Returns the formatted URL of the guild's icon, if one exists.
+    ///
+    /// Animated icons are returned as a `gif`, all others as a `webp`.
     pub fn icon_url(&self) -> Option<String> {
-        self.icon
-            .as_ref()
-            .map(|icon| format!(cdn!("/icons/{}/{}.webp"), self.id, icon))
+        icon_url(self.id, self.icon.as_ref(), None)
+    }
+
+    /// Returns the formatted URL of the guild's icon in the given format, if
+    /// one exists.
+    pub fn icon_url_with_format(&self, format: ImageFormat) -> Option<String> {
+        icon_url(self.id, self.icon.as_ref(), Some(format))
+    }
+
+    /// Gets the first cached [`Emoji`] of this guild with the given name,
+    /// matched case-sensitively.
+    pub fn emoji_named(&self, name: &str) -> Option<&Emoji> {
+        self.emojis.values().find(|emoji| emoji.name == name)
+    }
+
+    /// Counts the guild's cached emojis, returning the number of static and
+    /// animated emojis respectively.
+    pub fn emoji_counts(&self) -> (usize, usize) {
+        let animated = self.emojis.values().filter(|emoji| emoji.animated).count();
+
+        (self.emojis.len() - animated, animated)
+    }
+
+    /// Returns the maximum number of static emojis - and separately, of
+    /// animated emojis - the guild can have at its [`premium_tier`].
+    ///
+    /// [`premium_tier`]: Self::premium_tier
+    pub fn emoji_limit(&self) -> usize {
+        match self.premium_tier {
+            PremiumTier::Tier0 => 50,
+            PremiumTier::Tier1 => 100,
+            PremiumTier::Tier2 => 150,
+            PremiumTier::Tier3 => 250,
+        }
+    }
+
+    /// Returns the maximum size in bytes of a file uploaded to the guild at
+    /// its [`premium_tier`].
+    ///
+    /// [`premium_tier`]: Self::premium_tier
+    pub fn upload_limit(&self) -> u64 {
+        match self.premium_tier {
+            PremiumTier::Tier0 | PremiumTier::Tier1 => 8 * 1024 * 1024,
+            PremiumTier::Tier2 => 50 * 1024 * 1024,
+            PremiumTier::Tier3 => 100 * 1024 * 1024,
+        }
     }
 
     /// Gets all [`Emoji`]s of this guild via HTTP.
@@ -813,11 +1341,27 @@ impl Guild {
     }
 
     /// Gets an [`Emoji`] of this guild by its ID via HTTP.
+    ///
+    /// Use [`cached_emoji`] to look it up in the guild's emojis without a
+    /// request.
+    ///
+    /// [`cached_emoji`]: Self::cached_emoji
     #[inline]
     pub async fn emoji(&self, http: impl AsRef<Http>, emoji_id: EmojiId) -> Result<Emoji> {
         self.id.emoji(http, emoji_id).await
     }
 
+    /// Gets an [`Emoji`] of this guild by its ID from the guild's `emojis`
+    /// field, without making a request.
+    ///
+    /// The emoji may be outdated. Use [`emoji`] to retrieve a fresh copy.
+    ///
+    /// [`emoji`]: Self::emoji
+    #[inline]
+    pub fn cached_emoji(&self, emoji_id: EmojiId) -> Option<&Emoji> {
+        self.emojis.get(&emoji_id)
+    }
+
     /// Gets all integration of the guild.
     ///
     /// This performs a request over the REST API.
@@ -851,6 +1395,61 @@ impl Guild {
         self.id.invites(cache_http.http()).await
     }
 
+    /// Checks if the guild is currently available, i.e. not affected by an
+    /// outage.
+    ///
+    /// While a guild is unavailable, most of its data in the cache may be
+    /// stale.
+    #[inline]
+    pub fn is_available(&self) -> bool { !self.unavailable }
+
+    /// Resolves the [`afk_channel_id`] against the guild's channels.
+    ///
+    /// Returns `None` if no AFK channel is set or it is not in [`channels`].
+    ///
+    /// [`afk_channel_id`]: Self::afk_channel_id
+    /// [`channels`]: Self::channels
+    pub fn afk_channel(&self) -> Option<&GuildChannel> {
+        self.afk_channel_id.and_then(|id| self.channels.get(&id))
+    }
+
+    /// Resolves the [`system_channel_id`] against the guild's channels.
+    ///
+    /// Returns `None` if no system channel is set or it is not in
+    /// [`channels`].
+    ///
+    /// [`system_channel_id`]: Self::system_channel_id
+    /// [`channels`]: Self::channels
+    pub fn system_channel(&self) -> Option<&GuildChannel> {
+        self.system_channel_id.and_then(|id| self.channels.get(&id))
+    }
+
+    /// Checks if the guild has the given feature enabled, such as
+    /// `"VANITY_URL"`.
+    ///
+    /// Refer to [`features`] for a list of known features.
+    ///
+    /// [`features`]: Self::features
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
+    }
+
+    /// Checks if the guild is a Discord partner.
+    #[inline]
+    pub fn is_partnered(&self) -> bool { self.has_feature("PARTNERED") }
+
+    /// Checks if the guild is verified.
+    #[inline]
+    pub fn is_verified(&self) -> bool { self.has_feature("VERIFIED") }
+
+    /// Checks if the guild can set a vanity invite URL.
+    #[inline]
+    pub fn has_vanity_url(&self) -> bool { self.has_feature("VANITY_URL") }
+
+    /// Checks if the guild can set an animated icon.
+    #[inline]
+    pub fn supports_animated_icon(&self) -> bool { self.has_feature("ANIMATED_ICON") }
+
     /// Checks if the guild is 'large'. A guild is considered large if it has
     /// more than 250 members.
     #[inline]
@@ -877,11 +1476,39 @@ impl Guild {
     }
 
     /// Leaves the guild.
+    ///
+    /// **Note**: This does not update the cache. The guild, along with its
+    /// channels and their messages, stays cached until Discord sends the
+    /// corresponding [`GuildDeleteEvent`], so lookups made right after
+    /// leaving may still find it.
+    ///
+    /// [`GuildDeleteEvent`]: crate::model::event::GuildDeleteEvent
     #[inline]
     pub async fn leave(&self, http: impl AsRef<Http>) -> Result<()> {
         self.id.leave(&http).await
     }
 
+    /// Leaves the guild, returning a [`GuildLeaveSummary`] of the guild as it
+    /// was before leaving.
+    ///
+    /// This is useful for keeping a record of left guilds, as the guild will
+    /// be removed from the cache once Discord confirms the leave. Refer to
+    /// [`leave`] for details.
+    ///
+    /// [`leave`]: Self::leave
+    pub async fn leave_logging(&self, http: impl AsRef<Http>) -> Result<GuildLeaveSummary> {
+        let summary = GuildLeaveSummary {
+            id: self.id,
+            name: self.name.clone(),
+            member_count: self.member_count,
+            owner_id: self.owner_id,
+        };
+
+        self.id.leave(&http).await?;
+
+        Ok(summary)
+    }
+
     /// Gets a user's [`Member`] for the guild by Id.
     #[inline]
     pub async fn member(&self, cache_http: impl CacheHttp, user_id: impl Into<UserId>) -> Result<Member> {
@@ -903,6 +1530,31 @@ impl Guild {
         self.id.members(&http, limit, after).await
     }
 
+    /// Requests the guild's members from the gateway, so that [`members`] can
+    /// be completed for [`large`] guilds. Refer to [`GuildId::chunk_members`]
+    /// for more information.
+    ///
+    /// [`members`]: Self::members
+    /// [`large`]: Self::large
+    #[cfg(all(feature = "client", feature = "gateway"))]
+    #[inline]
+    pub async fn chunk_members(&self, shard_manager: &ShardManager, query: Option<&str>, limit: Option<u16>) -> bool {
+        self.id.chunk_members(shard_manager, query, limit).await
+    }
+
+    /// Gets all of the guild's members via HTTP, paginating through them 1000
+    /// at a time.
+    ///
+    /// Unlike the [`members`] field, this is not limited to the members that
+    /// have been received through the gateway. Refer to [`GuildId::all_members`] for
+    /// more information.
+    ///
+    /// [`members`]: Self::members
+    #[inline]
+    pub async fn all_members(&self, http: impl AsRef<Http>) -> Result<Vec<Member>> {
+        self.id.all_members(&http).await
+    }
+
     /// Gets a list of all the members (satisfying the status provided to the function) in this
     /// guild.
     pub fn members_with_status(&self, status: OnlineStatus) -> Vec<&Member> {
@@ -919,6 +1571,29 @@ impl Guild {
         members
     }
 
+    /// Gets a list of all the cached members of this guild that have the
+    /// given role.
+    pub fn members_with_role(&self, role_id: impl Into<RoleId>) -> Vec<&Member> {
+        let role_id = role_id.into();
+
+        self.members
+            .values()
+            .filter(|member| member.roles.contains(&role_id))
+            .collect()
+    }
+
+    /// Gets the cached [`Member`] of the guild's owner.
+    ///
+    /// Returns `None` if the owner is not in [`members`], such as when the
+    /// member list of a [`large`] guild has not been fully received yet.
+    ///
+    /// [`members`]: Self::members
+    /// [`large`]: Self::large
+    #[inline]
+    pub fn owner(&self) -> Option<&Member> {
+        self.members.get(&self.owner_id)
+    }
+
     /// Retrieves the first [`Member`] found that matches the name - with an
     /// optional discriminator - provided.
     ///
@@ -934,6 +1609,83 @@ impl Guild {
     /// - **username**: "zey"
     /// - **username and discriminator**: "zey#5479"
     pub fn member_named(&self, name: &str) -> Option<&Member> {
+        self._member_named(name, MemberKind::Any, |name, candidate| name == candidate)
+    }
+
+    /// Retrieves the first [`Member`] of the given [`MemberKind`] found that
+    /// matches the name - with an optional discriminator - provided.
+    ///
+    /// This is useful for commands that should only target humans, or only
+    /// bots. Refer to [`member_named`] for the supported types of searches.
+    ///
+    /// The other member lookup helpers have `_of_kind` variants as well, such
+    /// as [`members_starting_with_of_kind`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use serenity::model::guild::MemberKind;
+    ///
+    /// // assuming a `guild` has been bound
+    ///
+    /// let member = guild.member_named_of_kind("zey#5479", MemberKind::Human);
+    /// ```
+    ///
+    /// [`member_named`]: Self::member_named
+    /// [`members_starting_with_of_kind`]: Self::members_starting_with_of_kind
+    pub fn member_named_of_kind(&self, name: &str, kind: MemberKind) -> Option<&Member> {
+        self._member_named(name, kind, |name, candidate| name == candidate)
+    }
+
+    /// Retrieves a [`Member`] by name, falling back to a partial match if no
+    /// member is named exactly that.
+    ///
+    /// [`member_named`] is tried first. If it finds nothing, members whose
+    /// username - and then whose nickname - contains `name`, ignoring case,
+    /// are considered. If several members match, the one with the lowest Id
+    /// is returned.
+    ///
+    /// [`member_named`]: Self::member_named
+    pub fn member_named_fuzzy(&self, name: &str) -> Option<&Member> {
+        if let Some(member) = self.member_named(name) {
+            return Some(member);
+        }
+
+        self.members
+            .values()
+            .filter(|member| contains_case_insensitive(&member.user.name, name))
+            .min_by_key(|member| member.user.id)
+            .or_else(|| {
+                self.members
+                    .values()
+                    .filter(|member| member.nick.as_ref().map_or(false, |nick| contains_case_insensitive(nick, name)))
+                    .min_by_key(|member| member.user.id)
+            })
+    }
+
+    /// Retrieves the first [`Member`] found that matches the name - with an
+    /// optional discriminator - provided, ignoring diacritics.
+    ///
+    /// Both the searched name and the names of members are decomposed, and
+    /// any combining marks are removed before comparing them, so that a
+    /// member named "Zoë" would be found by "Zoe".
+    ///
+    /// Refer to [`member_named`] for the supported types of searches.
+    ///
+    /// [`member_named`]: Self::member_named
+    #[cfg(feature = "diacritics")]
+    pub fn member_named_ignoring_diacritics(&self, name: &str) -> Option<&Member> {
+        let name = strip_diacritics(name);
+
+        self._member_named(&name, MemberKind::Any, |name, candidate| name == strip_diacritics(candidate))
+    }
+
+    fn _member_named(
+        &self,
+        name: &str,
+        kind: MemberKind,
+        matches: impl Fn(&str, &str) -> bool,
+    ) -> Option<&Member> {
         let (name, discrim) = if let Some(pos) = name.rfind('#') {
             let split = name.split_at(pos + 1);
 
@@ -953,8 +1705,8 @@ impl Guild {
             (&name[..], None)
         };
 
-        for member in self.members.values() {
-            let name_matches = member.user.name == name;
+        for member in self.members.values().filter(|member| kind.matches(member)) {
+            let name_matches = matches(name, &member.user.name);
 
             let discrim_matches = match discrim {
                 Some(discrim) => member.user.discriminator == discrim,
@@ -968,7 +1720,8 @@ impl Guild {
 
         self.members
             .values()
-            .find(|member| member.nick.as_ref().map_or(false, |nick| nick == name))
+            .filter(|member| kind.matches(member))
+            .find(|member| member.nick.as_ref().map_or(false, |nick| matches(name, nick)))
     }
 
     /// Retrieves all [`Member`] that start with a given `String`.
@@ -988,12 +1741,26 @@ impl Guild {
     /// the names might have been changed by the user, the sorted list cannot
     /// account for this.
     pub async fn members_starting_with(&self, prefix: &str, case_sensitive: bool, sorted: bool) -> Vec<(&Member, String)> {
+        self.members_starting_with_of_kind(prefix, case_sensitive, sorted, MemberKind::Any).await
+    }
+
+    /// Like [`members_starting_with`], but only considers members of the given
+    /// [`MemberKind`].
+    ///
+    /// [`members_starting_with`]: Self::members_starting_with
+    pub async fn members_starting_with_of_kind(
+        &self,
+        prefix: &str,
+        case_sensitive: bool,
+        sorted: bool,
+        kind: MemberKind,
+    ) -> Vec<(&Member, String)> {
         fn starts_with(prefix: &str, case_sensitive: bool, name: &str) -> bool {
             case_sensitive && name.starts_with(prefix)
             || !case_sensitive && starts_with_case_insensitive(name, prefix)
         }
 
-        let mut members = futures::stream::iter(self.members.values())
+        let mut members = futures::stream::iter(self.members.values().filter(|member| kind.matches(member)))
             .filter_map(|member| async move {
                 let username = &member.user.name;
 
@@ -1054,13 +1821,28 @@ impl Guild {
     /// the names might have been changed by the user, the sorted list cannot
     /// account for this.
     pub async fn members_containing(&self, substring: &str, case_sensitive: bool, sorted: bool) -> Vec<(&Member, String)> {
+        self.members_containing_of_kind(substring, case_sensitive, sorted, MemberKind::Any).await
+    }
+
+    /// Like [`members_containing`], but only considers members of the given
+    /// [`MemberKind`].
+    ///
+    /// [`members_containing`]: Self::members_containing
+    pub async fn members_containing_of_kind(
+        &self,
+        substring: &str,
+        case_sensitive: bool,
+        sorted: bool,
+        kind: MemberKind,
+    ) -> Vec<(&Member, String)> {
         fn contains(substring: &str, case_sensitive: bool, name: &str) -> bool {
             case_sensitive && name.contains(substring)
             || !case_sensitive && contains_case_insensitive(name, substring)
         }
 
         let mut members = futures::stream::iter(self.members
-            .values())
+            .values()
+            .filter(|member| kind.matches(member)))
             .filter_map(|member| async move {
                 let username = &member.user.name;
 
@@ -1092,6 +1874,110 @@ impl Guild {
         }
     }
 
+    /// Retrieves all [`Member`]s whose username - or otherwise nick - starts
+    /// with the given `prefix`, ignoring diacritics.
+    ///
+    /// Both the `prefix` and the names of members are decomposed, and any
+    /// combining marks are removed before comparing them, so that a member
+    /// named "Zoë" would be found by "Zoe". The returned names are kept as
+    /// they are.
+    ///
+    /// Refer to [`members_starting_with`] for how `case_sensitive` and
+    /// `sorted` behave.
+    ///
+    /// [`members_starting_with`]: Self::members_starting_with
+    #[cfg(feature = "diacritics")]
+    pub fn members_starting_with_ignoring_diacritics(&self, prefix: &str, case_sensitive: bool, sorted: bool) -> Vec<(&Member, String)> {
+        self.members_starting_with_ignoring_diacritics_of_kind(prefix, case_sensitive, sorted, MemberKind::Any)
+    }
+
+    /// Like [`members_starting_with_ignoring_diacritics`], but only considers
+    /// members of the given [`MemberKind`].
+    ///
+    /// [`members_starting_with_ignoring_diacritics`]: Self::members_starting_with_ignoring_diacritics
+    #[cfg(feature = "diacritics")]
+    pub fn members_starting_with_ignoring_diacritics_of_kind(
+        &self,
+        prefix: &str,
+        case_sensitive: bool,
+        sorted: bool,
+        kind: MemberKind,
+    ) -> Vec<(&Member, String)> {
+        let prefix = strip_diacritics(prefix);
+
+        self._members_ignoring_diacritics(&prefix, sorted, kind, |name| {
+            case_sensitive && name.starts_with(&prefix[..])
+            || !case_sensitive && starts_with_case_insensitive(name, &prefix)
+        })
+    }
+
+    /// Retrieves all [`Member`]s whose username - or otherwise nick - contains
+    /// the given `substring`, ignoring diacritics.
+    ///
+    /// Names are compared as described in
+    /// [`members_starting_with_ignoring_diacritics`], and refer to
+    /// [`members_containing`] for how `case_sensitive` and `sorted` behave.
+    ///
+    /// [`members_starting_with_ignoring_diacritics`]: Self::members_starting_with_ignoring_diacritics
+    /// [`members_containing`]: Self::members_containing
+    #[cfg(feature = "diacritics")]
+    pub fn members_containing_ignoring_diacritics(&self, substring: &str, case_sensitive: bool, sorted: bool) -> Vec<(&Member, String)> {
+        self.members_containing_ignoring_diacritics_of_kind(substring, case_sensitive, sorted, MemberKind::Any)
+    }
+
+    /// Like [`members_containing_ignoring_diacritics`], but only considers
+    /// members of the given [`MemberKind`].
+    ///
+    /// [`members_containing_ignoring_diacritics`]: Self::members_containing_ignoring_diacritics
+    #[cfg(feature = "diacritics")]
+    pub fn members_containing_ignoring_diacritics_of_kind(
+        &self,
+        substring: &str,
+        case_sensitive: bool,
+        sorted: bool,
+        kind: MemberKind,
+    ) -> Vec<(&Member, String)> {
+        let substring = strip_diacritics(substring);
+
+        self._members_ignoring_diacritics(&substring, sorted, kind, |name| {
+            case_sensitive && name.contains(&substring[..])
+            || !case_sensitive && contains_case_insensitive(name, &substring)
+        })
+    }
+
+    #[cfg(feature = "diacritics")]
+    fn _members_ignoring_diacritics(
+        &self,
+        term: &str,
+        sorted: bool,
+        kind: MemberKind,
+        matches: impl Fn(&str) -> bool,
+    ) -> Vec<(&Member, String)> {
+        let mut members = self.members
+            .values()
+            .filter(|member| kind.matches(member))
+            .filter_map(|member| {
+                let candidates = std::iter::once(&member.user.name).chain(member.nick.as_ref());
+
+                for name in candidates {
+                    let stripped = strip_diacritics(name);
+
+                    if matches(&stripped) {
+                        return Some((member, name.to_string(), stripped));
+                    }
+                }
+
+                None
+            })
+            .collect::<Vec<_>>();
+
+        if sorted {
+            members.sort_by(|a, b| closest_to_origin(term, &a.2, &b.2));
+        }
+
+        members.into_iter().map(|(member, name, _)| (member, name)).collect()
+    }
+
     /// Retrieves a tuple of [`Member`]s containing a given `String` in
     /// their username as the first field and the name used for sorting
     /// as the second field.
@@ -1116,8 +2002,23 @@ impl Guild {
     /// the names might have been changed by the user, the sorted list cannot
     /// account for this.
     pub async fn members_username_containing(&self, substring: &str, case_sensitive: bool, sorted: bool) -> Vec<(&Member, String)> {
+        self.members_username_containing_of_kind(substring, case_sensitive, sorted, MemberKind::Any).await
+    }
+
+    /// Like [`members_username_containing`], but only considers members of the
+    /// given [`MemberKind`].
+    ///
+    /// [`members_username_containing`]: Self::members_username_containing
+    pub async fn members_username_containing_of_kind(
+        &self,
+        substring: &str,
+        case_sensitive: bool,
+        sorted: bool,
+        kind: MemberKind,
+    ) -> Vec<(&Member, String)> {
         let mut members = futures::stream::iter(self.members
-            .values())
+            .values()
+            .filter(|member| kind.matches(member)))
             .filter_map(|member| async move {
                 if case_sensitive {
                     let name = &member.user.name;
@@ -1177,8 +2078,23 @@ impl Guild {
     /// the names might have been changed by the user, the sorted list cannot
     /// account for this.
     pub async fn members_nick_containing(&self, substring: &str, case_sensitive: bool, sorted: bool) -> Vec<(&Member, String)> {
+        self.members_nick_containing_of_kind(substring, case_sensitive, sorted, MemberKind::Any).await
+    }
+
+    /// Like [`members_nick_containing`], but only considers members of the
+    /// given [`MemberKind`].
+    ///
+    /// [`members_nick_containing`]: Self::members_nick_containing
+    pub async fn members_nick_containing_of_kind(
+        &self,
+        substring: &str,
+        case_sensitive: bool,
+        sorted: bool,
+        kind: MemberKind,
+    ) -> Vec<(&Member, String)> {
         let mut members = futures::stream::iter(self.members
-            .values())
+            .values()
+            .filter(|member| kind.matches(member)))
             .filter_map(|member| async move {
                 let nick = match member.nick {
                     Some(ref nick) => nick.to_string(),
@@ -1272,19 +2188,77 @@ impl Guild {
     }
 
     /// Calculate a [`User`]'s permissions in a given channel in the guild.
+    ///
+    /// **Note**: If the user is not in [`members`], they are treated as only
+    /// having the permissions of `@everyone`. This includes users that have
+    /// left the guild, as well as members that are not cached. Use
+    /// [`user_permissions_in_strict`] when this is not acceptable, such as
+    /// for access control.
+    ///
+    /// [`members`]: Self::members
+    /// [`user_permissions_in_strict`]: Self::user_permissions_in_strict
     #[inline]
     pub fn user_permissions_in(&self, channel_id: impl Into<ChannelId>, user_id: impl Into<UserId>) -> Permissions {
-        self._user_permissions_in(channel_id.into(), user_id.into())
+        let user_id = user_id.into();
+
+        self._permissions_for_both(channel_id.into(), user_id, self.members.get(&user_id)).1
+    }
+
+    /// Calculate a [`Member`]'s permissions in a given channel in the guild.
+    ///
+    /// This is the same as [`user_permissions_in`], but avoids looking the
+    /// member up again when it is already at hand.
+    ///
+    /// [`user_permissions_in`]: Self::user_permissions_in
+    #[inline]
+    pub fn permissions_for_member(&self, channel_id: impl Into<ChannelId>, member: &Member) -> Permissions {
+        self._permissions_for_both(channel_id.into(), member.user.id, Some(member)).1
+    }
+
+    /// Calculate a [`User`]'s permissions in a given channel in the guild,
+    /// returning no permissions at all if the user is not in [`members`].
+    ///
+    /// Otherwise, this is the same as [`user_permissions_in`].
+    ///
+    /// [`members`]: Self::members
+    /// [`user_permissions_in`]: Self::user_permissions_in
+    pub fn user_permissions_in_strict(&self, channel_id: impl Into<ChannelId>, user_id: impl Into<UserId>) -> Permissions {
+        let user_id = user_id.into();
+
+        match self.members.get(&user_id) {
+            Some(member) => self._permissions_for_both(channel_id.into(), user_id, Some(member)).1,
+            None => Permissions::empty(),
+        }
+    }
+
+    /// Calculate a [`User`]'s guild-level permissions as well as their
+    /// permissions in a given channel in the guild, walking their roles only
+    /// once.
+    ///
+    /// Returns a tuple of `(guild_level, channel_level)`, where the second
+    /// value is the same as what [`user_permissions_in`] returns.
+    ///
+    /// [`user_permissions_in`]: Self::user_permissions_in
+    #[inline]
+    pub fn permissions_for_both(
+        &self,
+        channel_id: impl Into<ChannelId>,
+        user_id: impl Into<UserId>,
+    ) -> (Permissions, Permissions) {
+        let user_id = user_id.into();
+
+        self._permissions_for_both(channel_id.into(), user_id, self.members.get(&user_id))
     }
 
-    fn _user_permissions_in(
+    fn _permissions_for_both(
         &self,
         channel_id: ChannelId,
         user_id: UserId,
-    ) -> Permissions {
+        member: Option<&Member>,
+    ) -> (Permissions, Permissions) {
         // The owner has all permissions in all cases.
         if user_id == self.owner_id {
-            return Permissions::all();
+            return (Permissions::all(), Permissions::all());
         }
 
         // Start by retrieving the @everyone role's permissions.
@@ -1297,16 +2271,16 @@ impl Guild {
                     self.name
                 );
 
-                return Permissions::empty();
+                return (Permissions::empty(), Permissions::empty());
             },
         };
 
         // Create a base set of permissions, starting with `@everyone`s.
         let mut permissions = everyone.permissions;
 
-        let member = match self.members.get(&user_id) {
+        let member = match member {
             Some(member) => member,
-            None => return everyone.permissions,
+            None => return (everyone.permissions, everyone.permissions),
         };
 
         for &role in &member.roles {
@@ -1324,48 +2298,48 @@ impl Guild {
 
         // Administrators have all permissions in any channel.
         if permissions.contains(Permissions::ADMINISTRATOR) {
-            return Permissions::all();
+            return (Permissions::all(), Permissions::all());
         }
 
-        if let Some(channel) = self.channels.get(&channel_id) {
-            // If this is a text channel, then throw out voice permissions.
-            if channel.kind == ChannelType::Text {
-                permissions &= !(Permissions::CONNECT
-                    | Permissions::SPEAK
-                    | Permissions::MUTE_MEMBERS
-                    | Permissions::DEAFEN_MEMBERS
-                    | Permissions::MOVE_MEMBERS
-                    | Permissions::USE_VAD
-                    | Permissions::STREAM);
-            }
+        let guild_permissions = permissions;
 
+        if let Some(channel) = self.channels.get(&channel_id) {
             // Apply the permission overwrites for the channel for each of the
-            // overwrites that - first - applies to the member's roles, and then
-            // the member itself.
+            // overwrites that - first - applies to `@everyone`, then to the
+            // member's roles, and then the member itself.
             //
             // First apply the denied permission overwrites for each, then apply
             // the allowed.
 
-            let mut data = Vec::with_capacity(member.roles.len());
+            // @everyone
+            let everyone_overwrite = PermissionOverwriteType::Role(everyone.id);
+
+            for overwrite in &channel.permission_overwrites {
+                if overwrite.kind == everyone_overwrite {
+                    permissions = apply_overwrite(permissions, overwrite.deny, overwrite.allow);
+                }
+            }
 
             // Roles
+            //
+            // The overwrites of all of the member's roles are combined, so
+            // that an allow of any role takes precedence over a deny of any
+            // other role, regardless of their positions.
+            let mut deny = Permissions::empty();
+            let mut allow = Permissions::empty();
+
             for overwrite in &channel.permission_overwrites {
                 if let PermissionOverwriteType::Role(role) = overwrite.kind {
-                    if role.0 != self.id.0 && !member.roles.contains(&role) {
+                    if role == everyone.id || !member.roles.contains(&role) {
                         continue;
                     }
 
-                    if let Some(role) = self.roles.get(&role) {
-                        data.push((role.position, overwrite.deny, overwrite.allow));
-                    }
+                    deny |= overwrite.deny;
+                    allow |= overwrite.allow;
                 }
             }
 
-            data.sort_by(|a, b| a.0.cmp(&b.0));
-
-            for overwrite in data {
-                permissions = (permissions & !overwrite.1) | overwrite.2;
-            }
+            permissions = apply_overwrite(permissions, deny, allow);
 
             // Member
             for overwrite in &channel.permission_overwrites {
@@ -1373,8 +2347,12 @@ impl Guild {
                     continue;
                 }
 
-                permissions = (permissions & !overwrite.deny) | overwrite.allow;
+                permissions = apply_overwrite(permissions, overwrite.deny, overwrite.allow);
             }
+
+            // Throw out the permissions that have no use in this kind of
+            // channel, even if an overwrite granted them.
+            permissions &= !unusable_channel_permissions(channel.kind);
         } else {
             warn!(
                 "(╯°□°）╯︵ ┻━┻ Guild {} does not contain channel {}",
@@ -1390,7 +2368,7 @@ impl Guild {
 
         self.remove_unusable_permissions(&mut permissions);
 
-        permissions
+        (guild_permissions, permissions)
     }
 
     /// Calculate a [`Role`]'s permissions in a given channel in the guild.
@@ -1418,7 +2396,7 @@ impl Guild {
             for overwrite in &channel.permission_overwrites {
                 if let PermissionOverwriteType::Role(permissions_role_id) = overwrite.kind {
                     if permissions_role_id == role_id {
-                        permissions = (permissions & !overwrite.deny) | overwrite.allow;
+                        permissions = apply_overwrite(permissions, overwrite.deny, overwrite.allow);
 
                         break;
                     }
@@ -1451,6 +2429,9 @@ impl Guild {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
     /// if the current user does not have permission to perform bans.
     ///
+    /// Returns a [`ModelError::PruneDaysAmount`] if the number of days is not
+    /// between 1 and 30.
+    ///
     /// [Kick Members]: Permissions::KICK_MEMBERS
     pub async fn prune_count(&self, cache_http: impl CacheHttp, days: u16) -> Result<GuildPrune> {
         #[cfg(feature = "cache")]
@@ -1467,6 +2448,66 @@ impl Guild {
         self.id.prune_count(cache_http.http(), days).await
     }
 
+    /// Retrieves the count of the number of [`Member`]s that would be pruned
+    /// with the number of given days, if the current user is allowed to.
+    ///
+    /// This is the same as [`prune_count`], except that when the `cache` is
+    /// enabled and the current user is missing the [Kick Members]
+    /// permission, `None` is returned instead of an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::PruneDaysAmount`] if the number of days is not
+    /// between 1 and 30.
+    ///
+    /// [`prune_count`]: Self::prune_count
+    /// [Kick Members]: Permissions::KICK_MEMBERS
+    pub async fn try_prune_count(&self, cache_http: impl CacheHttp, days: u16) -> Result<Option<GuildPrune>> {
+        guild_id::check_prune_days(days)?;
+
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() && !self.has_perms(&cache_http, Permissions::KICK_MEMBERS).await {
+                return Ok(None);
+            }
+        }
+
+        self.id.prune_count(cache_http.http(), days).await.map(Some)
+    }
+
+    /// Retrieves the count of the number of [`Member`]s that would be pruned
+    /// with the number of given days, reusing the count last retrieved for the
+    /// same number of days if it is no older than `max_age`.
+    ///
+    /// This avoids repeatedly asking Discord for an expensive computation,
+    /// such as when displaying the count on a dashboard. Counts are stored in
+    /// the cache; if it is unavailable, this behaves like [`prune_count`].
+    ///
+    /// **Note**: Requires the [Kick Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Refer to [`prune_count`] for the possible errors.
+    ///
+    /// [`prune_count`]: Self::prune_count
+    /// [Kick Members]: Permissions::KICK_MEMBERS
+    #[cfg(feature = "cache")]
+    pub async fn prune_count_cached(&self, cache_http: impl CacheHttp, days: u16, max_age: Duration) -> Result<GuildPrune> {
+        if let Some(cache) = cache_http.cache() {
+            if let Some(prune) = cache.prune_count(self.id, days, max_age).await {
+                return Ok(prune);
+            }
+        }
+
+        let prune = self.prune_count(&cache_http, days).await?;
+
+        if let Some(cache) = cache_http.cache() {
+            cache.update_prune_count(self.id, days, prune).await;
+        }
+
+        Ok(prune)
+    }
+
     pub(crate) fn remove_unusable_permissions(&self, permissions: &mut Permissions) {
         // No SEND_MESSAGES => no message-sending-related actions
         // If the member does not have the `SEND_MESSAGES` permission, then
@@ -1495,11 +2536,81 @@ impl Guild {
     /// Although not required, you should specify all channels' positions,
     /// regardless of whether they were updated. Otherwise, positioning can
     /// sometimes get weird.
-    #[inline]
-    pub async fn reorder_channels<It>(&self, http: impl AsRef<Http>, channels: It) -> Result<()>
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to manage channels.
+    ///
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    pub async fn reorder_channels<It>(&self, cache_http: impl CacheHttp, channels: It) -> Result<()>
     where It: IntoIterator<Item = (ChannelId, u64)>
     {
-        self.id.reorder_channels(&http, channels).await
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::MANAGE_CHANNELS;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.reorder_channels(cache_http.http(), channels).await
+    }
+
+    /// Closes any gaps in the positions of the guild's channels, such as those
+    /// left behind after deleting channels.
+    ///
+    /// Channels are renumbered from `0` within each category - with
+    /// categories themselves numbered separately - keeping their relative
+    /// order. All positions are then sent in a single [`reorder_channels`]
+    /// call.
+    ///
+    /// No request is made if the positions are already contiguous.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// [`reorder_channels`]: Self::reorder_channels
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    pub async fn normalize_channel_positions(&self, cache_http: impl CacheHttp) -> Result<()> {
+        let positions = self.normalized_channel_positions();
+
+        let unchanged = positions.iter().all(|(id, position)| {
+            self.channels.get(id).map_or(false, |channel| channel.position == *position as i64)
+        });
+
+        if unchanged {
+            return Ok(());
+        }
+
+        self.reorder_channels(cache_http, positions).await
+    }
+
+    fn normalized_channel_positions(&self) -> Vec<(ChannelId, u64)> {
+        let mut groups: HashMap<(Option<ChannelId>, bool), Vec<&GuildChannel>> = HashMap::new();
+
+        for channel in self.channels.values() {
+            let key = (channel.category_id, channel.kind == ChannelType::Category);
+
+            groups.entry(key).or_insert_with(Vec::new).push(channel);
+        }
+
+        let mut positions = Vec::with_capacity(self.channels.len());
+
+        for channels in groups.values_mut() {
+            channels.sort_by_key(|channel| (channel.position, channel.id));
+
+            positions.extend(channels
+                .iter()
+                .enumerate()
+                .map(|(position, channel)| (channel.id, position as u64)));
+        }
+
+        positions
     }
 
     /// Returns the Id of the shard associated with the guild.
@@ -1544,9 +2655,9 @@ impl Guild {
 
     /// Returns the formatted URL of the guild's splash image, if one exists.
     pub fn splash_url(&self) -> Option<String> {
-        self.icon
+        self.splash
             .as_ref()
-            .map(|icon| format!(cdn!("/splashes/{}/{}.webp"), self.id, icon))
+            .map(|splash| format!(cdn!("/splashes/{}/{}.webp"), self.id, splash))
     }
 
     /// Starts an integration sync for the given integration Id.
@@ -1570,6 +2681,9 @@ impl Guild {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
     /// if the current user does not have permission to perform bans.
     ///
+    /// Returns a [`ModelError::PruneDaysAmount`] if the number of days is not
+    /// between 1 and 30.
+    ///
     /// [Kick Members]: Permissions::KICK_MEMBERS
     pub async fn start_prune(&self, cache_http: impl CacheHttp, days: u16) -> Result<GuildPrune> {
         #[cfg(feature = "cache")]
@@ -1586,6 +2700,84 @@ impl Guild {
         self.id.start_prune(cache_http.http(), days).await
     }
 
+    /// Syncs a [`GuildTemplate`] with the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn sync_template(&self, cache_http: impl CacheHttp, code: &str) -> Result<GuildTemplate> {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::MANAGE_GUILD;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.sync_template(cache_http.http(), code).await
+    }
+
+    /// Retrieves the guild's [`GuildTemplate`]s.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn templates(&self, cache_http: impl CacheHttp) -> Result<Vec<GuildTemplate>> {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::MANAGE_GUILD;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.templates(cache_http.http()).await
+    }
+
+    /// Transfers ownership of the guild to the given [`User`], updating
+    /// [`owner_id`] on success.
+    ///
+    /// **Note**: Requires the current user to be the owner of the guild.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, then returns a [`ModelError::InvalidUser`]
+    /// if the current user is not the guild owner.
+    ///
+    /// [`owner_id`]: Self::owner_id
+    pub async fn transfer_ownership(&mut self, cache_http: impl CacheHttp, user_id: impl Into<UserId>) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if self.owner_id != cache.current_user().await.id {
+                    return Err(Error::Model(ModelError::InvalidUser));
+                }
+            }
+        }
+
+        let user_id = user_id.into();
+        let guild = self.id.edit(cache_http.http(), |g| g.owner(user_id)).await?;
+        self.merge_partial(guild);
+
+        Ok(())
+    }
+
     /// Unbans the given [`User`] from the guild.
     ///
     /// **Note**: Requires the [Ban Members] permission.
@@ -1611,14 +2803,82 @@ impl Guild {
         self.id.unban(&cache_http.http(), user_id).await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Unbans each of the given [`User`]s from the guild, optionally with a
+    /// reason shown in the audit log.
+    ///
+    /// Every user is unbanned in turn and the result for each is returned
+    /// alongside its Id, so that a single failure does not abort the rest.
+    /// A user who is not banned counts as a success, which makes it safe to
+    /// re-run the same unban list.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to perform bans. This is
+    /// checked once, before any user is unbanned.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub async fn unban_many<U>(
+        &self,
+        cache_http: impl CacheHttp,
+        users: &[U],
+        reason: Option<&str>,
+    ) -> Result<Vec<(UserId, Result<()>)>>
+    where U: Into<UserId> + Copy
+    {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::BAN_MEMBERS;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        let reason = reason.unwrap_or("");
+        let mut results = Vec::with_capacity(users.len());
+
+        for &user in users {
+            let user_id = user.into();
+
+            let result = match self.id.unban_with_reason(cache_http.http(), user_id, reason).await {
+                Err(Error::Http(why)) if why.status_code() == Some(StatusCode::NOT_FOUND) => Ok(()),
+                other => other,
+            };
+
+            results.push((user_id, result));
+        }
+
+        Ok(results)
+    }
+
+    /// Retrieve's the guild's vanity URL code, if it has one.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to manage the guild.
+    ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
-    #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
-        self.id.vanity_url(&http).await
+    pub async fn vanity_url(&self, cache_http: impl CacheHttp) -> Result<Option<String>> {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::MANAGE_GUILD;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.vanity_url(cache_http.http()).await
     }
 
     /// Retrieves the guild's webhooks.
@@ -1631,6 +2891,34 @@ impl Guild {
         self.id.webhooks(&http).await
     }
 
+    /// Retrieves the webhooks of a single channel in the guild.
+    ///
+    /// This is cheaper than filtering the guild-wide list from [`webhooks`].
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to manage webhooks.
+    ///
+    /// [`webhooks`]: Self::webhooks
+    /// [Manage Webhooks]: Permissions::MANAGE_WEBHOOKS
+    pub async fn channel_webhooks(&self, cache_http: impl CacheHttp, channel_id: impl Into<ChannelId>) -> Result<Vec<Webhook>> {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::MANAGE_WEBHOOKS;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        channel_id.into().webhooks(cache_http.http()).await
+    }
+
     /// Obtain a reference to a role by its name.
     ///
     /// **Note**: If two or more roles have the same name, obtained reference will be one of
@@ -1670,171 +2958,383 @@ impl Guild {
     pub fn role_by_name(&self, role_name: &str) -> Option<&Role> {
         self.roles.values().find(|role| role_name == role.name)
     }
-}
-
-impl<'de> Deserialize<'de> for Guild {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
-        let mut map = JsonMap::deserialize(deserializer)?;
 
-        let id = map.get("id")
-            .and_then(|x| x.as_str())
-            .and_then(|x| x.parse::<u64>().ok());
+    /// Retrieves the [`Role`]s of a member, sorted by position from highest to
+    /// lowest.
+    ///
+    /// If `include_everyone` is `true`, the `@everyone` role is included
+    /// last. Roles of the member that are not in [`roles`] are skipped.
+    ///
+    /// Returns an empty list if the user is not in [`members`].
+    ///
+    /// [`members`]: Self::members
+    /// [`roles`]: Self::roles
+    pub fn member_roles(&self, user_id: impl Into<UserId>, include_everyone: bool) -> Vec<&Role> {
+        let member = match self.members.get(&user_id.into()) {
+            Some(member) => member,
+            None => return Vec::new(),
+        };
 
-        if let Some(guild_id) = id {
-            if let Some(array) = map.get_mut("channels").and_then(|x| x.as_array_mut()) {
-                for value in array {
-                    if let Some(channel) = value.as_object_mut() {
-                        channel
-                            .insert("guild_id".to_string(), Value::Number(Number::from(guild_id)));
-                    }
-                }
+        let mut roles = Vec::with_capacity(member.roles.len() + 1);
+
+        for role_id in &member.roles {
+            if let Some(role) = self.roles.get(role_id) {
+                roles.push(role);
+            } else {
+                warn!(
+                    "(╯°□°）╯︵ ┻━┻ {} on {} has non-existent role {:?}",
+                    member.user.id,
+                    self.id,
+                    role_id,
+                );
             }
+        }
 
-            if let Some(array) = map.get_mut("members").and_then(|x| x.as_array_mut()) {
-                for value in array {
-                    if let Some(member) = value.as_object_mut() {
-                        member
-                            .insert("guild_id".to_string(), Value::Number(Number::from(guild_id)));
-                    }
-                }
+        roles.sort_by(|a, b| b.position.cmp(&a.position).then(a.id.cmp(&b.id)));
+
+        if include_everyone {
+            if let Some(everyone) = self.roles.get(&RoleId(self.id.0)) {
+                roles.push(everyone);
             }
+        }
 
-            if let Some(array) = map.get_mut("roles").and_then(|x| x.as_array_mut()) {
-                for value in array {
-                    if let Some(role) = value.as_object_mut() {
-                        role
-                            .insert("guild_id".to_string(), Value::Number(Number::from(guild_id)));
-                    }
-                }
+        roles
+    }
+
+    /// Retrieves the highest [`Role`] of a member, by [`position`].
+    ///
+    /// Returns `None` if the user is not in [`members`], or if the member has
+    /// no roles other than `@everyone`.
+    ///
+    /// [`members`]: Self::members
+    /// [`position`]: Role::position
+    #[inline]
+    pub fn highest_role_of(&self, user_id: impl Into<UserId>) -> Option<&Role> {
+        self.member_roles(user_id, false).into_iter().next()
+    }
+
+    /// Whether the `actor` is above the `target` in the role hierarchy, and
+    /// is therefore allowed to moderate them, such as by kicking or banning.
+    ///
+    /// The owner can moderate anyone, while nobody can moderate the owner.
+    /// Otherwise, the position of the actor's [highest role] must be strictly
+    /// greater than that of the target's.
+    ///
+    /// This only checks the hierarchy, not whether the actor has the
+    /// permission for a given action. Returns `false` if either user is not
+    /// in [`members`].
+    ///
+    /// [highest role]: Self::highest_role_of
+    /// [`members`]: Self::members
+    pub fn can_moderate(&self, actor: impl Into<UserId>, target: impl Into<UserId>) -> bool {
+        let (actor, target) = (actor.into(), target.into());
+
+        if target == self.owner_id {
+            return false;
+        }
+
+        if !self.members.contains_key(&actor) || !self.members.contains_key(&target) {
+            return false;
+        }
+
+        if actor == self.owner_id {
+            return true;
+        }
+
+        let position = |user_id| self.highest_role_of(user_id).map_or(0, |role| role.position);
+
+        position(actor) > position(target)
+    }
+
+    /// Predicts whether the member passes the guild's [`verification_level`]
+    /// and is therefore able to send messages.
+    ///
+    /// Members with any role, and the owner, are not subject to verification.
+    /// Otherwise the account age is derived from the user's Id, and the time
+    /// spent in the guild from [`Member::joined_at`].
+    ///
+    /// Some requirements can not be checked, and are assumed to be met:
+    ///
+    /// - [`VerificationLevel::Low`] requires a verified email;
+    /// - [`VerificationLevel::Higher`] requires a verified phone.
+    ///
+    /// A member whose join date is unknown is assumed not to have been in the
+    /// guild long enough for [`VerificationLevel::High`].
+    ///
+    /// [`verification_level`]: Self::verification_level
+    pub fn meets_verification_level(&self, member: &Member) -> bool {
+        self._meets_verification_level(member, Utc::now())
+    }
+
+    fn _meets_verification_level(&self, member: &Member, now: DateTime<Utc>) -> bool {
+        if member.user.id == self.owner_id || !member.roles.is_empty() {
+            return true;
+        }
+
+        if self.verification_level >= VerificationLevel::Medium
+            && now - member.user.id.created_at() < chrono::Duration::minutes(5)
+        {
+            return false;
+        }
+
+        if self.verification_level >= VerificationLevel::High {
+            match member.joined_at {
+                Some(joined_at) if now - joined_at >= chrono::Duration::minutes(10) => {},
+                _ => return false,
+            }
+        }
+
+        true
+    }
+}
+
+impl Guild {
+    /// Updates the guild with the data of a [`PartialGuild`], such as one
+    /// retrieved via [`Guild::get`].
+    ///
+    /// Only the fields shared by both are updated, so gateway-only data such
+    /// as [`members`], [`presences`], [`voice_states`], and [`channels`] is
+    /// left untouched.
+    ///
+    /// [`members`]: Self::members
+    /// [`presences`]: Self::presences
+    /// [`voice_states`]: Self::voice_states
+    /// [`channels`]: Self::channels
+    pub fn merge_partial(&mut self, partial: PartialGuild) {
+        self.afk_channel_id = partial.afk_channel_id;
+        self.afk_timeout = partial.afk_timeout;
+        self.default_message_notifications = partial.default_message_notifications;
+        self.emojis = partial.emojis;
+        self.features = partial.features;
+        self.icon = partial.icon;
+        self.mfa_level = partial.mfa_level;
+        self.name = partial.name;
+        self.owner_id = partial.owner_id;
+        self.region = partial.region;
+        self.roles = partial.roles;
+        self.splash = partial.splash;
+        self.verification_level = partial.verification_level;
+        self.description = partial.description;
+        self.premium_tier = partial.premium_tier;
+        self.premium_subscription_count = partial.premium_subscription_count;
+        self.banner = partial.banner;
+        self.vanity_url_code = partial.vanity_url_code;
+    }
+
+    /// Adds a [`Member`] who joined the guild, incrementing the
+    /// [`member_count`] unless the member was already present.
+    ///
+    /// Returns the previously stored member, if any.
+    ///
+    /// [`member_count`]: Self::member_count
+    pub fn insert_member(&mut self, member: Member) -> Option<Member> {
+        let old = self.members.insert(member.user.id, member);
+
+        if old.is_none() {
+            self.member_count += 1;
+        }
+
+        old
+    }
+
+    /// Removes a [`Member`] who left the guild, decrementing the
+    /// [`member_count`].
+    ///
+    /// The count is decremented even if the member was not cached, as large
+    /// guilds do not have their full member list available.
+    ///
+    /// [`member_count`]: Self::member_count
+    pub fn remove_member(&mut self, user_id: UserId) -> Option<Member> {
+        self.member_count = self.member_count.saturating_sub(1);
+
+        self.members.remove(&user_id)
+    }
+
+    /// Sets the [`member_count`] to the number of cached [`members`].
+    ///
+    /// This should only be called when the full member list is known to be
+    /// cached, such as after all member chunks have been received.
+    ///
+    /// [`member_count`]: Self::member_count
+    /// [`members`]: Self::members
+    pub fn resync_member_count(&mut self) {
+        self.member_count = self.members.len() as u64;
+    }
+
+    /// Returns the current number of members in the guild.
+    ///
+    /// With the `cache` feature enabled, and once the guild is fully chunked -
+    /// that is, at least [`member_count`] members are cached - this is the
+    /// number of [`members`], which gateway events keep up to date as members
+    /// join and leave.
+    ///
+    /// In all other cases, such as when the bot lacks the `GUILD_MEMBERS`
+    /// intent and only itself and members in voice channels are cached, this
+    /// is the [`member_count`] field as sent by Discord.
+    ///
+    /// [`member_count`]: Self::member_count
+    /// [`members`]: Self::members
+    pub fn current_member_count(&self) -> u64 {
+        #[cfg(feature = "cache")]
+        {
+            let cached = self.members.len() as u64;
+
+            if cached >= self.member_count {
+                return cached;
+            }
+        }
+
+        self.member_count
+    }
+}
+
+impl<'de> Deserialize<'de> for Guild {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let mut map = JsonMap::deserialize(deserializer)?;
+
+        let id = map.get("id")
+            .and_then(|x| x.as_str())
+            .and_then(|x| x.parse::<u64>().ok());
+
+        if let Some(guild_id) = id {
+            for key in &["channels", "members", "roles", "emojis"] {
+                insert_guild_ids(&mut map, key, guild_id);
             }
         }
 
+        // Names the field that failed to deserialize, as in
+        // `guild.member_count: missing field`.
+        let field_error = |field: &'static str| move |why: serde_json::Error| {
+            D::Error::custom(format_args!("guild.{}: {}", field, why))
+        };
+
         let afk_channel_id = match map.remove("afk_channel_id") {
             Some(v) => serde_json::from_value::<Option<ChannelId>>(v)
-                .map_err(DeError::custom)?,
+                .map_err(field_error("afk_channel_id"))?,
             None => None,
         };
         let afk_timeout = map.remove("afk_timeout")
-            .ok_or_else(|| DeError::custom("expected guild afk_timeout"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(u64::deserialize)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("afk_timeout"))?;
         let application_id = match map.remove("application_id") {
             Some(v) => serde_json::from_value::<Option<ApplicationId>>(v)
-                .map_err(DeError::custom)?,
+                .map_err(field_error("application_id"))?,
             None => None,
         };
         let channels = map.remove("channels")
-            .ok_or_else(|| DeError::custom("expected guild channels"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(deserialize_guild_channels)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("channels"))?;
         let default_message_notifications = map.remove("default_message_notifications")
-            .ok_or_else(|| {
-                DeError::custom("expected guild default_message_notifications")
-            })
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(DefaultMessageNotificationLevel::deserialize)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("default_message_notifications"))?;
         let emojis = map.remove("emojis")
-            .ok_or_else(|| DeError::custom("expected guild emojis"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(deserialize_emojis)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("emojis"))?;
         let explicit_content_filter = map.remove("explicit_content_filter")
-            .ok_or_else(|| DeError::custom(
-                "expected guild explicit_content_filter"
-            ))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(ExplicitContentFilter::deserialize)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("explicit_content_filter"))?;
         let features = map.remove("features")
-            .ok_or_else(|| DeError::custom("expected guild features"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(serde_json::from_value::<Vec<String>>)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("features"))?;
         let icon = match map.remove("icon") {
-            Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
+            Some(v) => Option::<String>::deserialize(v).map_err(field_error("icon"))?,
             None => None,
         };
         let id = map.remove("id")
-            .ok_or_else(|| DeError::custom("expected guild id"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(GuildId::deserialize)
-            .map_err(DeError::custom)?;
-        let joined_at = map.remove("joined_at")
-            .ok_or_else(|| DeError::custom("expected guild joined_at"))
-            .and_then(DateTime::deserialize)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("id"))?;
+        let joined_at = match map.remove("joined_at") {
+            Some(v) => Option::<DateTime<Utc>>::deserialize(v).map_err(field_error("joined_at"))?,
+            None => None,
+        };
         let large = map.remove("large")
-            .ok_or_else(|| DeError::custom("expected guild large"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(bool::deserialize)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("large"))?;
         let member_count = map.remove("member_count")
-            .ok_or_else(|| DeError::custom("expected guild member_count"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(u64::deserialize)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("member_count"))?;
         let members = map.remove("members")
-            .ok_or_else(|| DeError::custom("expected guild members"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(deserialize_members)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("members"))?;
         let mfa_level = map.remove("mfa_level")
-            .ok_or_else(|| DeError::custom("expected guild mfa_level"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(MfaLevel::deserialize)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("mfa_level"))?;
         let name = map.remove("name")
-            .ok_or_else(|| DeError::custom("expected guild name"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(String::deserialize)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("name"))?;
         let owner_id = map.remove("owner_id")
-            .ok_or_else(|| DeError::custom("expected guild owner_id"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(UserId::deserialize)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("owner_id"))?;
         let presences = map.remove("presences")
-            .ok_or_else(|| DeError::custom("expected guild presences"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(deserialize_presences)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("presences"))?;
         let region = map.remove("region")
-            .ok_or_else(|| DeError::custom("expected guild region"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(String::deserialize)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("region"))?;
         let roles = map.remove("roles")
-            .ok_or_else(|| DeError::custom("expected guild roles"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(deserialize_roles)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("roles"))?;
         let splash = match map.remove("splash") {
-            Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
+            Some(v) => Option::<String>::deserialize(v).map_err(field_error("splash"))?,
             None => None,
         };
         let system_channel_id = match map.remove("system_channel_id") {
-            Some(v) => Option::<ChannelId>::deserialize(v).map_err(DeError::custom)?,
+            Some(v) => Option::<ChannelId>::deserialize(v)
+                .map_err(field_error("system_channel_id"))?,
             None => None,
         };
+        let unavailable = match map.remove("unavailable") {
+            Some(v) => bool::deserialize(v).map_err(field_error("unavailable"))?,
+            None => false,
+        };
         let verification_level = map.remove("verification_level")
-            .ok_or_else(|| DeError::custom("expected guild verification_level"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(VerificationLevel::deserialize)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("verification_level"))?;
         let voice_states = map.remove("voice_states")
-            .ok_or_else(|| DeError::custom("expected guild voice_states"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(deserialize_voice_states)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("voice_states"))?;
         let description = match map.remove("description") {
-            Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
+            Some(v) => Option::<String>::deserialize(v).map_err(field_error("description"))?,
             None => None,
         };
         let premium_tier = match map.remove("premium_tier") {
-            Some(v) => PremiumTier::deserialize(v).map_err(DeError::custom)?,
+            Some(v) => PremiumTier::deserialize(v).map_err(field_error("premium_tier"))?,
             None => PremiumTier::default(),
         };
         let premium_subscription_count = match map.remove("premium_subscription_count") {
             Some(Value::Null) | None => 0,
-            Some(v) => u64::deserialize(v).map_err(DeError::custom)?,
+            Some(v) => u64::deserialize(v).map_err(field_error("premium_subscription_count"))?,
         };
         let banner = match map.remove("banner") {
-            Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
+            Some(v) => Option::<String>::deserialize(v).map_err(field_error("banner"))?,
             None => None,
         };
         let vanity_url_code = match map.remove("vanity_url_code") {
-            Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
+            Some(v) => Option::<String>::deserialize(v).map_err(field_error("vanity_url_code"))?,
             None => None,
         };
         let preferred_locale = map.remove("preferred_locale")
-            .ok_or_else(|| DeError::custom("expected preferred locale"))
+            .ok_or_else(|| DeError::custom("missing field"))
             .and_then(String::deserialize)
-            .map_err(DeError::custom)?;
+            .map_err(field_error("preferred_locale"))?;
 
         Ok(Self {
             afk_channel_id,
@@ -1859,6 +3359,7 @@ impl<'de> Deserialize<'de> for Guild {
             roles,
             splash,
             system_channel_id,
+            unavailable,
             verification_level,
             voice_states,
             description,
@@ -1871,6 +3372,70 @@ impl<'de> Deserialize<'de> for Guild {
     }
 }
 
+#[cfg(any(feature = "model", feature = "utils"))]
+fn icon_url(guild_id: GuildId, hash: Option<&String>, format: Option<ImageFormat>) -> Option<String> {
+    hash.map(|hash| {
+        let format = format.unwrap_or_else(|| if hash.starts_with("a_") {
+            ImageFormat::Gif
+        } else {
+            ImageFormat::WebP
+        });
+
+        cdn!("/icons/{}/{}.{}", guild_id, hash, format.extension())
+    })
+}
+
+/// Applies the denied and then the allowed permissions of a channel
+/// overwrite.
+///
+/// Discord only ever grants [`Permissions::ADMINISTRATOR`] through roles, so
+/// it is masked out of both sets before applying them.
+#[cfg(feature = "model")]
+fn apply_overwrite(permissions: Permissions, deny: Permissions, allow: Permissions) -> Permissions {
+    let deny = deny & !Permissions::ADMINISTRATOR;
+    let allow = allow & !Permissions::ADMINISTRATOR;
+
+    (permissions & !deny) | allow
+}
+
+/// Returns the permissions that have no use in a channel of the given kind:
+/// voice permissions outside of voice channels, and text permissions in voice
+/// channels and categories.
+#[cfg(feature = "model")]
+fn unusable_channel_permissions(kind: ChannelType) -> Permissions {
+    let voice = Permissions::CONNECT
+        | Permissions::SPEAK
+        | Permissions::MUTE_MEMBERS
+        | Permissions::DEAFEN_MEMBERS
+        | Permissions::MOVE_MEMBERS
+        | Permissions::USE_VAD
+        | Permissions::STREAM
+        | Permissions::PRIORITY_SPEAKER;
+    let text = Permissions::ADD_REACTIONS
+        | Permissions::SEND_MESSAGES
+        | Permissions::SEND_TTS_MESSAGES
+        | Permissions::MANAGE_MESSAGES
+        | Permissions::EMBED_LINKS
+        | Permissions::ATTACH_FILES
+        | Permissions::READ_MESSAGE_HISTORY
+        | Permissions::MENTION_EVERYONE
+        | Permissions::USE_EXTERNAL_EMOJIS;
+
+    match kind {
+        ChannelType::Voice => text,
+        ChannelType::Category => text | voice,
+        _ => voice,
+    }
+}
+
+/// Decomposes a `&str` and removes all combining marks, such as accents.
+#[cfg(feature = "diacritics")]
+fn strip_diacritics(s: &str) -> String {
+    use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+    s.nfkd().filter(|c| !is_combining_mark(*c)).collect()
+}
+
 /// Checks if a `&str` contains another `&str`.
 #[cfg(feature = "model")]
 fn contains_case_insensitive(to_look_at: &str, to_find: &str) -> bool {
@@ -1928,6 +3493,48 @@ pub struct GuildEmbed {
     pub enabled: bool,
 }
 
+/// A guild's channels, grouped by their [`ChannelType`].
+///
+/// Each group is sorted by the channels' positions.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildChannels {
+    /// The channel categories.
+    pub categories: Vec<GuildChannel>,
+    /// The text and news channels.
+    pub text: Vec<GuildChannel>,
+    /// The voice channels.
+    pub voice: Vec<GuildChannel>,
+    /// Channels of any other kind, such as store channels.
+    pub other: Vec<GuildChannel>,
+}
+
+impl From<HashMap<ChannelId, GuildChannel>> for GuildChannels {
+    fn from(channels: HashMap<ChannelId, GuildChannel>) -> GuildChannels {
+        let mut structured = GuildChannels::default();
+
+        for channel in channels.into_iter().map(|(_, channel)| channel) {
+            match channel.kind {
+                ChannelType::Category => structured.categories.push(channel),
+                ChannelType::Text | ChannelType::News => structured.text.push(channel),
+                ChannelType::Voice => structured.voice.push(channel),
+                _ => structured.other.push(channel),
+            }
+        }
+
+        for group in &mut [
+            &mut structured.categories,
+            &mut structured.text,
+            &mut structured.voice,
+            &mut structured.other,
+        ] {
+            group.sort_by_key(|channel| (channel.position, channel.id));
+        }
+
+        structured
+    }
+}
+
 /// Representation of the number of members that would be pruned by a guild
 /// prune operation.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
@@ -1936,6 +3543,21 @@ pub struct GuildPrune {
     pub pruned: u64,
 }
 
+/// A record of a guild that the current user has left, as returned by
+/// [`Guild::leave_logging`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildLeaveSummary {
+    /// The Id of the guild that was left.
+    pub id: GuildId,
+    /// The name of the guild at the time it was left.
+    pub name: String,
+    /// The number of members in the guild at the time it was left.
+    pub member_count: u64,
+    /// The Id of the [`User`] who owned the guild at the time it was left.
+    pub owner_id: UserId,
+}
+
 /// Basic information about a guild.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildInfo {
@@ -1958,10 +3580,20 @@ pub struct GuildInfo {
 #[cfg(any(feature = "model", feature = "utils"))]
 impl GuildInfo {
     /// Returns the formatted URL of the guild's icon, if the guild has an icon.
+    ///
+    /// Animated icons are returned as a `gif`, all others as a `webp`.
     pub fn icon_url(&self) -> Option<String> {
-        self.icon
-            .as_ref()
-            .map(|icon| format!(cdn!("/icons/{}/{}.webp"), self.id, icon))
+        icon_url(self.id, self.icon.as_ref(), None)
+    }
+}
+
+impl GuildContainer {
+    /// Retrieves the Id of the contained guild.
+    pub fn id(&self) -> GuildId {
+        match self {
+            GuildContainer::Guild(guild) => guild.id,
+            GuildContainer::Id(id) => *id,
+        }
     }
 }
 
@@ -1981,9 +3613,9 @@ impl From<u64> for GuildContainer {
 impl InviteGuild {
     /// Returns the formatted URL of the guild's splash image, if one exists.
     pub fn splash_url(&self) -> Option<String> {
-        self.icon
+        self.splash_hash
             .as_ref()
-            .map(|icon| format!(cdn!("/splashes/{}/{}.webp"), self.id, icon))
+            .map(|splash| format!(cdn!("/splashes/{}/{}.webp"), self.id, splash))
     }
 }
 
@@ -2150,6 +3782,49 @@ impl Region {
     }
 }
 
+impl std::str::FromStr for Region {
+    type Err = RegionParseError;
+
+    /// Parses a region from its [`name`].
+    ///
+    /// [`name`]: Self::name
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        Ok(match s {
+            "amsterdam" => Region::Amsterdam,
+            "brazil" => Region::Brazil,
+            "eu-central" => Region::EuCentral,
+            "eu-west" => Region::EuWest,
+            "frankfurt" => Region::Frankfurt,
+            "hongkong" => Region::HongKong,
+            "japan" => Region::Japan,
+            "london" => Region::London,
+            "russia" => Region::Russia,
+            "singapore" => Region::Singapore,
+            "sydney" => Region::Sydney,
+            "us-central" => Region::UsCentral,
+            "us-east" => Region::UsEast,
+            "us-south" => Region::UsSouth,
+            "us-west" => Region::UsWest,
+            "vip-amsterdam" => Region::VipAmsterdam,
+            "vip-us-east" => Region::VipUsEast,
+            "vip-us-west" => Region::VipUsWest,
+            _ => return Err(RegionParseError),
+        })
+    }
+}
+
+/// The error returned when parsing a [`Region`] from an unknown name.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RegionParseError;
+
+impl std::fmt::Display for RegionParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("unknown region")
+    }
+}
+
+impl std::error::Error for RegionParseError {}
+
 /// The level to set as criteria prior to a user being able to send
 /// messages in a [`Guild`].
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
@@ -2195,6 +3870,7 @@ mod test {
     mod model {
         use chrono::prelude::*;
         use crate::model::prelude::*;
+        use crate::utils::Colour;
         use std::collections::*;
 
 
@@ -2205,6 +3881,7 @@ mod test {
                 bot: true,
                 discriminator: 1432,
                 name: "test".to_string(),
+                global_name: None,
             }
         }
 
@@ -2217,6 +3894,7 @@ mod test {
             let u = gen_user();
 
             Member {
+                avatar: None,
                 deaf: false,
                 guild_id: GuildId(1),
                 joined_at: Some(dt),
@@ -2227,6 +3905,59 @@ mod test {
             }
         }
 
+        fn gen_role(id: u64, position: i64, permissions: Permissions) -> Role {
+            Role {
+                id: RoleId(id),
+                guild_id: GuildId(1),
+                colour: Colour::default(),
+                hoist: false,
+                managed: false,
+                mentionable: false,
+                name: format!("role-{}", id),
+                permissions,
+                position,
+                flags: RoleFlags::default(),
+            }
+        }
+
+        fn gen_channel(id: u64, kind: ChannelType, permission_overwrites: Vec<PermissionOverwrite>) -> GuildChannel {
+            GuildChannel {
+                id: ChannelId(id),
+                bitrate: None,
+                category_id: None,
+                guild_id: GuildId(1),
+                kind,
+                last_message_id: None,
+                last_pin_timestamp: None,
+                name: "general".to_string(),
+                permission_overwrites,
+                position: 0,
+                topic: None,
+                user_limit: None,
+                nsfw: false,
+                slow_mode_rate: None,
+            }
+        }
+
+        /// Generates a guild owned by someone else, in which the generated
+        /// member has a single role with [`Permissions::MANAGE_MESSAGES`].
+        fn gen_permissions_guild(permission_overwrites: Vec<PermissionOverwrite>) -> Guild {
+            let mut guild = gen();
+            guild.owner_id = UserId(1);
+
+            let everyone = gen_role(1, 0, Permissions::READ_MESSAGES | Permissions::SEND_MESSAGES);
+            let role = gen_role(2, 1, Permissions::MANAGE_MESSAGES);
+            guild.roles.insert(everyone.id, everyone);
+            guild.roles.insert(role.id, role);
+
+            guild.members.get_mut(&UserId(210)).unwrap().roles.push(RoleId(2));
+
+            let channel = gen_channel(3, ChannelType::Text, permission_overwrites);
+            guild.channels.insert(channel.id, channel);
+
+            guild
+        }
+
         fn gen() -> Guild {
             let u = gen_user();
             let m = gen_member();
@@ -2256,7 +3987,7 @@ mod test {
                 features: vec1,
                 icon: Some("/avatars/210/a_aaa.webp?size=1024".to_string()),
                 id: GuildId(1),
-                joined_at: dt,
+                joined_at: Some(dt),
                 large: false,
                 member_count: 1,
                 members: hm3,
@@ -2274,6 +4005,7 @@ mod test {
                 application_id: Some(ApplicationId(0)),
                 explicit_content_filter: ExplicitContentFilter::None,
                 system_channel_id: Some(ChannelId(0)),
+                unavailable: false,
                 premium_subscription_count: 12,
                 banner: None,
                 vanity_url_code: Some("bruhmoment".to_string()),
@@ -2300,5 +4032,778 @@ mod test {
 
             assert_eq!(lhs, gen_member().display_name());
         }
+
+        #[test]
+        fn permissions_for_both_applies_overwrites_to_channel_only() {
+            let overwrites = vec![PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::MANAGE_MESSAGES,
+                kind: PermissionOverwriteType::Role(RoleId(2)),
+            }];
+            let guild = gen_permissions_guild(overwrites);
+
+            let (guild_level, channel_level) = guild.permissions_for_both(ChannelId(3), UserId(210));
+
+            assert!(guild_level.manage_messages());
+            assert!(!channel_level.manage_messages());
+            assert!(channel_level.send_messages());
+            assert_eq!(channel_level, guild.user_permissions_in(ChannelId(3), UserId(210)));
+        }
+
+        #[test]
+        fn merge_partial_keeps_gateway_data() {
+            let mut guild = gen();
+            let partial = PartialGuild {
+                id: guild.id,
+                afk_channel_id: None,
+                afk_timeout: 300,
+                default_message_notifications: DefaultMessageNotificationLevel::Mentions,
+                widget_channel_id: None,
+                widget_enabled: false,
+                emojis: HashMap::new(),
+                features: vec!["BANNER".to_string()],
+                icon: None,
+                mfa_level: MfaLevel::None,
+                name: "Linguine".to_string(),
+                owner_id: UserId(210),
+                region: "eu-west".to_string(),
+                roles: HashMap::new(),
+                splash: None,
+                verification_level: VerificationLevel::High,
+                description: None,
+                premium_tier: PremiumTier::Tier2,
+                premium_subscription_count: 15,
+                banner: Some("banner".to_string()),
+                vanity_url_code: None,
+            };
+
+            guild.merge_partial(partial);
+
+            assert_eq!(guild.name, "Linguine");
+            assert_eq!(guild.afk_timeout, 300);
+            assert_eq!(guild.verification_level, VerificationLevel::High);
+            assert_eq!(guild.premium_subscription_count, 15);
+            assert_eq!(guild.members.len(), 1);
+        }
+
+        #[test]
+        fn role_flags_preserve_unknown_bits() {
+            let flags: RoleFlags = serde_json::from_str("3").unwrap();
+
+            assert!(flags.in_prompt());
+            assert_eq!(serde_json::to_string(&flags).unwrap(), "3");
+        }
+
+        #[test]
+        fn overwrites_cannot_deny_administrator() {
+            let overwrites = vec![PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::ADMINISTRATOR,
+                kind: PermissionOverwriteType::Role(RoleId(2)),
+            }];
+            let mut guild = gen_permissions_guild(overwrites);
+            guild.roles.get_mut(&RoleId(2)).unwrap().permissions = Permissions::ADMINISTRATOR;
+
+            assert_eq!(guild.user_permissions_in(ChannelId(3), UserId(210)), Permissions::all());
+            assert_eq!(guild.role_permissions_in(ChannelId(3), RoleId(2)), Some(Permissions::all()));
+        }
+
+        #[test]
+        fn overwrites_cannot_grant_administrator() {
+            let overwrites = vec![PermissionOverwrite {
+                allow: Permissions::ADMINISTRATOR,
+                deny: Permissions::empty(),
+                kind: PermissionOverwriteType::Member(UserId(210)),
+            }];
+            let guild = gen_permissions_guild(overwrites);
+
+            let permissions = guild.user_permissions_in(ChannelId(3), UserId(210));
+
+            assert!(!permissions.contains(Permissions::ADMINISTRATOR));
+            assert!(permissions.manage_messages());
+        }
+
+        #[test]
+        fn guild_channels_are_grouped_by_kind() {
+            let mut channels = HashMap::new();
+
+            let mut text = gen_channel(1, ChannelType::Text, vec![]);
+            text.position = 2;
+            let news = gen_channel(2, ChannelType::News, vec![]);
+            let voice = gen_channel(3, ChannelType::Voice, vec![]);
+            let category = gen_channel(4, ChannelType::Category, vec![]);
+
+            for channel in vec![text, news, voice, category] {
+                channels.insert(channel.id, channel);
+            }
+
+            let structured = GuildChannels::from(channels);
+
+            let text_ids = structured.text.iter().map(|c| c.id).collect::<Vec<_>>();
+            assert_eq!(text_ids, vec![ChannelId(2), ChannelId(1)]);
+            assert_eq!(structured.voice.len(), 1);
+            assert_eq!(structured.categories.len(), 1);
+            assert!(structured.other.is_empty());
+        }
+
+        #[test]
+        fn everyone_overwrite_applies_to_members() {
+            let overwrites = vec![PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::READ_MESSAGES,
+                kind: PermissionOverwriteType::Role(RoleId(1)),
+            }];
+            let guild = gen_permissions_guild(overwrites);
+
+            let permissions = guild.user_permissions_in(ChannelId(3), UserId(210));
+
+            assert!(!permissions.read_messages());
+        }
+
+        #[test]
+        fn permissions_depend_on_channel_kind() {
+            let mut guild = gen_permissions_guild(vec![]);
+            guild.roles.get_mut(&RoleId(2)).unwrap().permissions |= Permissions::CONNECT;
+
+            let kinds = vec![
+                (ChannelType::Text, true, false),
+                (ChannelType::News, true, false),
+                (ChannelType::Store, true, false),
+                (ChannelType::Voice, false, true),
+                (ChannelType::Category, false, false),
+            ];
+
+            for (kind, text, voice) in kinds {
+                let channel = gen_channel(4, kind, vec![]);
+                guild.channels.insert(channel.id, channel);
+
+                let permissions = guild.user_permissions_in(ChannelId(4), UserId(210));
+
+                assert!(permissions.read_messages(), "{:?}", kind);
+                assert_eq!(permissions.send_messages(), text, "{:?}", kind);
+                assert_eq!(permissions.manage_messages(), text, "{:?}", kind);
+                assert_eq!(permissions.connect(), voice, "{:?}", kind);
+            }
+        }
+
+        #[test]
+        fn members_with_role() {
+            let guild = gen_permissions_guild(vec![]);
+
+            let members = guild.members_with_role(RoleId(2));
+
+            assert_eq!(members.len(), 1);
+            assert_eq!(members[0].user.id, UserId(210));
+            assert!(guild.members_with_role(RoleId(3)).is_empty());
+        }
+
+        #[test]
+        fn role_by_name_is_case_sensitive() {
+            let guild = gen_permissions_guild(vec![]);
+
+            assert_eq!(guild.role_by_name("role-2").unwrap().id, RoleId(2));
+            assert!(guild.role_by_name("Role-2").is_none());
+        }
+
+        #[test]
+        fn highest_role_of() {
+            let mut guild = gen_permissions_guild(vec![]);
+
+            assert_eq!(guild.highest_role_of(UserId(210)).unwrap().id, RoleId(2));
+            assert!(guild.highest_role_of(UserId(999)).is_none());
+
+            guild.members.get_mut(&UserId(210)).unwrap().roles.clear();
+
+            assert!(guild.highest_role_of(UserId(210)).is_none());
+        }
+
+        #[test]
+        fn can_moderate() {
+            let mut guild = gen_permissions_guild(vec![]);
+
+            let mut member = gen_member();
+            member.user.id = UserId(211);
+            guild.members.insert(member.user.id, member.clone());
+
+            member.user.id = UserId(1);
+            guild.members.insert(member.user.id, member);
+
+            assert!(guild.can_moderate(UserId(210), UserId(211)));
+            assert!(!guild.can_moderate(UserId(211), UserId(210)));
+            assert!(!guild.can_moderate(UserId(210), UserId(210)));
+            assert!(guild.can_moderate(UserId(1), UserId(210)));
+            assert!(!guild.can_moderate(UserId(210), UserId(1)));
+            assert!(!guild.can_moderate(UserId(210), UserId(999)));
+        }
+
+        #[test]
+        fn owner() {
+            let mut guild = gen();
+
+            assert_eq!(guild.owner().unwrap().user.id, UserId(210));
+
+            guild.owner_id = UserId(1);
+
+            assert!(guild.owner().is_none());
+        }
+
+        #[test]
+        fn member_roles_are_sorted_by_position() {
+            let mut guild = gen_permissions_guild(vec![]);
+            let role = gen_role(3, 2, Permissions::empty());
+            guild.roles.insert(role.id, role);
+
+            let member = guild.members.get_mut(&UserId(210)).unwrap();
+            member.roles.push(RoleId(3));
+            member.roles.push(RoleId(4));
+
+            let ids = |roles: Vec<&Role>| roles.iter().map(|role| role.id).collect::<Vec<_>>();
+
+            assert_eq!(ids(guild.member_roles(UserId(210), false)), vec![RoleId(3), RoleId(2)]);
+            assert_eq!(ids(guild.member_roles(UserId(210), true)), vec![RoleId(3), RoleId(2), RoleId(1)]);
+            assert!(guild.member_roles(UserId(999), true).is_empty());
+        }
+
+        #[test]
+        fn permissions_for_member_matches_user_permissions_in() {
+            let overwrites = vec![PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::SEND_MESSAGES,
+                kind: PermissionOverwriteType::Role(RoleId(2)),
+            }];
+            let guild = gen_permissions_guild(overwrites);
+            let member = &guild.members[&UserId(210)];
+
+            assert_eq!(
+                guild.permissions_for_member(ChannelId(3), member),
+                guild.user_permissions_in(ChannelId(3), UserId(210)),
+            );
+        }
+
+        #[test]
+        fn role_overwrite_allows_beat_denies() {
+            for &(lower, higher) in &[
+                (Permissions::empty(), Permissions::SEND_MESSAGES),
+                (Permissions::SEND_MESSAGES, Permissions::empty()),
+            ] {
+                let overwrites = vec![
+                    PermissionOverwrite {
+                        allow: lower,
+                        deny: Permissions::SEND_MESSAGES - lower,
+                        kind: PermissionOverwriteType::Role(RoleId(2)),
+                    },
+                    PermissionOverwrite {
+                        allow: higher,
+                        deny: Permissions::SEND_MESSAGES - higher,
+                        kind: PermissionOverwriteType::Role(RoleId(3)),
+                    },
+                ];
+                let mut guild = gen_permissions_guild(overwrites);
+                let role = gen_role(3, 2, Permissions::empty());
+                guild.roles.insert(role.id, role);
+                guild.members.get_mut(&UserId(210)).unwrap().roles.push(RoleId(3));
+
+                let permissions = guild.user_permissions_in(ChannelId(3), UserId(210));
+
+                assert!(permissions.send_messages());
+            }
+        }
+
+        #[test]
+        fn non_members_get_everyone_permissions() {
+            let guild = gen_permissions_guild(vec![]);
+
+            let permissions = guild.user_permissions_in(ChannelId(3), UserId(999));
+
+            assert!(permissions.read_messages());
+            assert!(permissions.send_messages());
+            assert!(!permissions.manage_messages());
+        }
+
+        #[test]
+        fn non_members_get_no_permissions_when_strict() {
+            let guild = gen_permissions_guild(vec![]);
+
+            assert!(guild.user_permissions_in_strict(ChannelId(3), UserId(999)).is_empty());
+            assert!(guild.user_permissions_in_strict(ChannelId(3), UserId(210)).manage_messages());
+        }
+
+        #[test]
+        fn locked_channel_strips_actionable_permissions() {
+            let overwrites = vec![PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::READ_MESSAGES,
+                kind: PermissionOverwriteType::Role(RoleId(1)),
+            }];
+            let mut guild = gen_permissions_guild(overwrites);
+            guild.roles.get_mut(&RoleId(2)).unwrap().permissions |= Permissions::KICK_MEMBERS;
+
+            let (guild_level, channel_level) = guild.permissions_for_both(ChannelId(3), UserId(210));
+
+            assert!(guild_level.kick_members());
+            assert!(!channel_level.read_messages());
+            assert!(!channel_level.kick_members());
+        }
+
+        #[test]
+        fn role_overwrites_apply_after_everyone_overwrite() {
+            let overwrites = vec![
+                PermissionOverwrite {
+                    allow: Permissions::READ_MESSAGES,
+                    deny: Permissions::empty(),
+                    kind: PermissionOverwriteType::Role(RoleId(2)),
+                },
+                PermissionOverwrite {
+                    allow: Permissions::empty(),
+                    deny: Permissions::READ_MESSAGES,
+                    kind: PermissionOverwriteType::Role(RoleId(1)),
+                },
+            ];
+            let guild = gen_permissions_guild(overwrites);
+
+            let permissions = guild.user_permissions_in(ChannelId(3), UserId(210));
+
+            assert!(permissions.read_messages());
+        }
+
+        #[cfg(feature = "diacritics")]
+        #[test]
+        fn member_named_ignoring_diacritics() {
+            let mut guild = gen();
+            guild.members.get_mut(&UserId(210)).unwrap().user.name = "tést".to_string();
+
+            assert!(guild.member_named("test#1432").is_none());
+
+            let lhs = guild
+                .member_named_ignoring_diacritics("test#1432")
+                .unwrap()
+                .display_name();
+
+            assert_eq!(lhs, gen_member().display_name());
+        }
+
+        #[cfg(feature = "diacritics")]
+        #[test]
+        fn members_searched_ignoring_diacritics() {
+            let mut guild = gen();
+            guild.members.get_mut(&UserId(210)).unwrap().user.name = "tést".to_string();
+
+            let found = guild.members_starting_with_ignoring_diacritics("TE", false, true);
+            assert_eq!(found.len(), 1);
+            assert_eq!(found[0].1, "tést");
+
+            assert_eq!(guild.members_containing_ignoring_diacritics("es", true, false).len(), 1);
+            assert!(guild.members_containing_ignoring_diacritics("ES", true, false).is_empty());
+        }
+
+        #[test]
+        fn display_name_falls_back_to_global_name_then_username() {
+            let mut member = gen_member();
+            member.user.global_name = Some("Global".to_string());
+
+            assert_eq!(member.display_name().as_str(), "aaaa");
+
+            member.nick = None;
+
+            assert_eq!(member.display_name().as_str(), "Global");
+
+            member.user.global_name = None;
+
+            assert_eq!(member.display_name().as_str(), "test");
+        }
+
+        #[test]
+        fn member_deserializes_avatar_and_global_name() {
+            let value = serde_json::json!({
+                "avatar": "b4a",
+                "deaf": false,
+                "guild_id": "1",
+                "joined_at": null,
+                "mute": false,
+                "nick": null,
+                "roles": [],
+                "user": {
+                    "id": "210",
+                    "avatar": null,
+                    "discriminator": "0",
+                    "username": "test",
+                    "global_name": "Test",
+                },
+            });
+
+            let member: Member = serde_json::from_value(value).unwrap();
+
+            assert_eq!(member.avatar.as_deref(), Some("b4a"));
+            assert_eq!(member.user.global_name.as_deref(), Some("Test"));
+            assert_eq!(member.display_name().as_str(), "Test");
+        }
+
+        #[test]
+        fn member_named_of_kind() {
+            let guild = gen();
+
+            assert!(guild.member_named_of_kind("test#1432", MemberKind::Any).is_some());
+            assert!(guild.member_named_of_kind("test#1432", MemberKind::Bot).is_some());
+            assert!(guild.member_named_of_kind("test#1432", MemberKind::Human).is_none());
+            assert!(guild.member_named_of_kind("aaaa", MemberKind::Human).is_none());
+        }
+
+        #[tokio::test]
+        async fn members_containing_of_kind() {
+            let guild = gen();
+
+            assert_eq!(guild.members_containing("es", false, false).await.len(), 1);
+            assert_eq!(guild.members_containing_of_kind("es", false, false, MemberKind::Bot).await.len(), 1);
+            assert!(guild.members_containing_of_kind("es", false, false, MemberKind::Human).await.is_empty());
+            assert!(guild.members_starting_with_of_kind("aa", false, false, MemberKind::Human).await.is_empty());
+        }
+
+        #[test]
+        fn normalized_channel_positions_close_gaps() {
+            let mut guild = gen();
+
+            let mut category = gen_channel(10, ChannelType::Category, vec![]);
+            category.position = 4;
+            let mut first = gen_channel(11, ChannelType::Text, vec![]);
+            first.category_id = Some(ChannelId(10));
+            first.position = 2;
+            let mut second = gen_channel(12, ChannelType::Voice, vec![]);
+            second.category_id = Some(ChannelId(10));
+            second.position = 7;
+            let mut uncategorized = gen_channel(13, ChannelType::Text, vec![]);
+            uncategorized.position = 3;
+
+            for channel in vec![category, first, second, uncategorized] {
+                guild.channels.insert(channel.id, channel);
+            }
+
+            let mut positions = guild.normalized_channel_positions();
+            positions.sort();
+
+            assert_eq!(positions, vec![
+                (ChannelId(10), 0),
+                (ChannelId(11), 0),
+                (ChannelId(12), 1),
+                (ChannelId(13), 0),
+            ]);
+        }
+
+        #[cfg(feature = "cache")]
+        #[test]
+        fn current_member_count_prefers_fully_cached_members() {
+            let mut guild = gen();
+            guild.member_count = 5;
+
+            assert_eq!(guild.current_member_count(), 5);
+
+            guild.member_count = 1;
+
+            assert_eq!(guild.current_member_count(), 1);
+
+            let mut member = gen_member();
+            member.user.id = UserId(211);
+            guild.members.insert(member.user.id, member);
+
+            assert_eq!(guild.current_member_count(), 2);
+        }
+
+        #[test]
+        fn member_count_tracks_joins_and_leaves() {
+            let mut guild = gen();
+
+            let mut member = gen_member();
+            member.user.id = UserId(211);
+
+            assert!(guild.insert_member(member.clone()).is_none());
+            assert_eq!(guild.member_count, 2);
+
+            assert!(guild.insert_member(member).is_some());
+            assert_eq!(guild.member_count, 2);
+
+            assert!(guild.remove_member(UserId(211)).is_some());
+            assert_eq!(guild.member_count, 1);
+
+            guild.member_count = 50;
+            guild.resync_member_count();
+            assert_eq!(guild.member_count, 1);
+        }
+
+        #[test]
+        fn deserialize_errors_name_the_field() {
+            let why = serde_json::from_str::<Guild>("{}").unwrap_err();
+            assert!(why.to_string().starts_with("guild.afk_timeout: missing field"));
+
+            let why = serde_json::from_str::<Guild>(r#"{"afk_timeout": "300"}"#).unwrap_err();
+            assert!(why.to_string().starts_with("guild.afk_timeout: invalid type"));
+        }
+
+        #[test]
+        fn splash_url_uses_splash_hash() {
+            let mut guild = gen();
+            guild.icon = Some("icon".to_string());
+            guild.splash = None;
+
+            assert_eq!(guild.splash_url(), None);
+
+            guild.splash = Some("splash".to_string());
+
+            assert_eq!(
+                guild.splash_url().as_deref(),
+                Some("https://cdn.discordapp.com/splashes/1/splash.webp"),
+            );
+        }
+
+        #[test]
+        fn icon_url_detects_animated_icons() {
+            let mut guild = gen();
+            guild.icon = Some("abc".to_string());
+
+            assert_eq!(
+                guild.icon_url().as_deref(),
+                Some("https://cdn.discordapp.com/icons/1/abc.webp"),
+            );
+
+            guild.icon = Some("a_abc".to_string());
+
+            assert_eq!(
+                guild.icon_url().as_deref(),
+                Some("https://cdn.discordapp.com/icons/1/a_abc.gif"),
+            );
+            assert_eq!(
+                guild.icon_url_with_format(ImageFormat::Png).as_deref(),
+                Some("https://cdn.discordapp.com/icons/1/a_abc.png"),
+            );
+        }
+
+        #[test]
+        fn member_named_fuzzy() {
+            let mut guild = gen();
+
+            let mut member = gen_member();
+            member.user.id = UserId(211);
+            member.user.name = "Zeyla".to_string();
+            member.nick = None;
+            guild.members.insert(member.user.id, member);
+
+            assert_eq!(guild.member_named_fuzzy("test").map(|m| m.user.id), Some(UserId(210)));
+            assert_eq!(guild.member_named_fuzzy("zey").map(|m| m.user.id), Some(UserId(211)));
+            assert_eq!(guild.member_named_fuzzy("AA").map(|m| m.user.id), Some(UserId(210)));
+            assert!(guild.member_named_fuzzy("nobody").is_none());
+        }
+
+        #[tokio::test]
+        async fn default_channel_is_topmost_readable_text_channel() {
+            let mut guild = gen_permissions_guild(vec![]);
+            guild.channels.get_mut(&ChannelId(3)).unwrap().position = 5;
+
+            let hidden = gen_channel(4, ChannelType::Text, vec![PermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: Permissions::READ_MESSAGES,
+                kind: PermissionOverwriteType::Role(RoleId(1)),
+            }]);
+            let voice = gen_channel(5, ChannelType::Voice, vec![]);
+            guild.channels.insert(hidden.id, hidden);
+            guild.channels.insert(voice.id, voice);
+
+            let channel = guild.default_channel(UserId(210)).await.map(|c| c.id);
+            assert_eq!(channel, Some(ChannelId(3)));
+
+            let mut higher = gen_channel(6, ChannelType::Text, vec![]);
+            higher.position = 2;
+            guild.channels.insert(higher.id, higher);
+
+            let channel = guild.default_channel(UserId(210)).await.map(|c| c.id);
+            assert_eq!(channel, Some(ChannelId(6)));
+
+            guild.channels.retain(|id, _| *id == ChannelId(4));
+            assert!(guild.default_channel(UserId(210)).await.is_none());
+        }
+
+        #[test]
+        fn role_is_above() {
+            let low = gen_role(2, 1, Permissions::empty());
+            let high = gen_role(3, 2, Permissions::empty());
+            let tied = gen_role(4, 2, Permissions::empty());
+
+            assert!(high.is_above(&low));
+            assert!(!low.is_above(&high));
+            assert!(!high.is_above(&tied));
+            assert!(!tied.is_above(&high));
+        }
+
+        #[test]
+        fn deserialize_sets_emoji_guild_id() {
+            let mut value = serde_json::to_value(gen()).unwrap();
+            value["id"] = serde_json::json!("1");
+            value["emojis"] = serde_json::json!([{
+                "id": "7",
+                "name": "blobface",
+                "managed": false,
+                "require_colons": true,
+                "roles": [],
+            }]);
+
+            let guild = serde_json::from_value::<Guild>(value).unwrap();
+            assert_eq!(guild.emojis[&EmojiId(7)].guild_id, Some(GuildId(1)));
+        }
+
+        #[test]
+        fn emoji_name_validation() {
+            use crate::model::ModelError;
+            use crate::Error;
+            use super::super::guild_id::check_emoji_name;
+
+            assert!(check_emoji_name("ok").is_ok());
+            assert!(check_emoji_name("blob_face_2").is_ok());
+
+            for name in &["", "a", "blob face", "blob-face", "blöb"] {
+                match check_emoji_name(name) {
+                    Err(Error::Model(ModelError::InvalidEmojiName(n))) => assert_eq!(&n, name),
+                    other => panic!("expected InvalidEmojiName for {:?}, got {:?}", name, other),
+                }
+            }
+        }
+
+        #[test]
+        fn region_round_trips_through_name() {
+            let regions = [
+                Region::Amsterdam,
+                Region::Brazil,
+                Region::EuCentral,
+                Region::EuWest,
+                Region::Frankfurt,
+                Region::HongKong,
+                Region::Japan,
+                Region::London,
+                Region::Russia,
+                Region::Singapore,
+                Region::Sydney,
+                Region::UsCentral,
+                Region::UsEast,
+                Region::UsSouth,
+                Region::UsWest,
+                Region::VipAmsterdam,
+                Region::VipUsEast,
+                Region::VipUsWest,
+            ];
+
+            for region in &regions {
+                assert_eq!(region.name().parse::<Region>(), Ok(*region));
+            }
+
+            assert_eq!("mars".parse::<Region>(), Err(RegionParseError));
+        }
+
+        #[test]
+        fn meets_verification_level() {
+            let mut guild = gen();
+            guild.owner_id = UserId(1);
+            guild.verification_level = VerificationLevel::High;
+
+            let mut member = gen_member();
+            let joined_at = member.joined_at.unwrap();
+
+            let now = joined_at + chrono::Duration::minutes(5);
+            assert!(!guild._meets_verification_level(&member, now));
+
+            guild.verification_level = VerificationLevel::Medium;
+            assert!(guild._meets_verification_level(&member, now));
+
+            guild.verification_level = VerificationLevel::Higher;
+            let now = joined_at + chrono::Duration::minutes(10);
+            assert!(guild._meets_verification_level(&member, now));
+
+            member.joined_at = None;
+            assert!(!guild._meets_verification_level(&member, now));
+
+            member.roles.push(RoleId(2));
+            assert!(guild._meets_verification_level(&member, now));
+
+            let mut fresh = gen_member();
+            fresh.user.id = UserId(member.user.id.0 | (1 << 40));
+            fresh.roles.clear();
+            let now = fresh.user.id.created_at() + chrono::Duration::minutes(1);
+            fresh.joined_at = Some(now - chrono::Duration::minutes(30));
+            guild.verification_level = VerificationLevel::Medium;
+            assert!(!guild._meets_verification_level(&fresh, now));
+        }
+
+        #[test]
+        fn guild_container_id() {
+            assert_eq!(GuildContainer::from(7).id(), GuildId(7));
+            assert_eq!(GuildContainer::from(GuildId(8)).id(), GuildId(8));
+        }
+
+        #[test]
+        fn feature_helpers() {
+            let mut guild = gen();
+            guild.features = vec!["VERIFIED".to_string(), "ANIMATED_ICON".to_string()];
+
+            assert!(guild.has_feature("VERIFIED"));
+            assert!(guild.is_verified());
+            assert!(guild.supports_animated_icon());
+            assert!(!guild.is_partnered());
+            assert!(!guild.has_vanity_url());
+        }
+
+        #[test]
+        fn afk_and_system_channel() {
+            let mut guild = gen_permissions_guild(Vec::new());
+            guild.afk_channel_id = Some(ChannelId(3));
+            guild.system_channel_id = Some(ChannelId(4));
+
+            assert_eq!(guild.afk_channel().map(|c| c.id), Some(ChannelId(3)));
+            assert!(guild.system_channel().is_none());
+        }
+
+        #[test]
+        fn emoji_named() {
+            let mut guild = gen();
+            let emoji = Emoji {
+                animated: false,
+                guild_id: Some(guild.id),
+                id: EmojiId(5),
+                name: "ferris".to_string(),
+                managed: false,
+                require_colons: true,
+                roles: Vec::new(),
+            };
+            guild.emojis.insert(emoji.id, emoji);
+
+            assert_eq!(guild.emoji_named("ferris").map(|e| e.id), Some(EmojiId(5)));
+            assert!(guild.emoji_named("Ferris").is_none());
+        }
+
+        #[test]
+        fn premium_tier_limits() {
+            let mut guild = gen();
+            guild.premium_tier = PremiumTier::Tier2;
+
+            for (id, animated) in [(1, false), (2, true), (3, false)].iter() {
+                let emoji = Emoji {
+                    animated: *animated,
+                    guild_id: Some(guild.id),
+                    id: EmojiId(*id),
+                    name: format!("emoji{}", id),
+                    managed: false,
+                    require_colons: true,
+                    roles: Vec::new(),
+                };
+                guild.emojis.insert(emoji.id, emoji);
+            }
+
+            assert_eq!(guild.emoji_counts(), (2, 1));
+            assert_eq!(guild.emoji_limit(), 150);
+            assert_eq!(guild.upload_limit(), 50 * 1024 * 1024);
+        }
+
+        #[test]
+        fn deserialize_without_joined_at() {
+            let mut value = serde_json::to_value(gen()).unwrap();
+            value.as_object_mut().unwrap().remove("joined_at");
+
+            let guild = serde_json::from_value::<Guild>(value).unwrap();
+            assert!(guild.joined_at.is_none());
+        }
     }
 }
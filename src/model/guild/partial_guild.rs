@@ -1,13 +1,13 @@
 use serde::de::Error as DeError;
 
 use crate::model::prelude::*;
-use crate::model::utils::{deserialize_emojis, deserialize_roles};
+use crate::model::utils::{deserialize_emojis, deserialize_roles, insert_guild_ids};
 
 #[cfg(feature = "model")]
-use crate::builder::{CreateChannel, EditGuild, EditMember, EditRole};
+use crate::builder::{CreateChannel, EditGuild, EditIntegration, EditMember, EditRole};
 #[cfg(feature = "model")]
 use crate::http::{Http, CacheHttp};
-#[cfg(all(feature = "cache", feature = "utils", feature = "client"))]
+#[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(feature = "collector")]
 use crate::client::bridge::gateway::ShardMessenger;
@@ -101,6 +101,34 @@ impl PartialGuild {
         self.id.bans(&http).await
     }
 
+    /// Gets a page of the guild's bans. Refer to [`GuildId::bans_paginated`]
+    /// for how the page is selected.
+    ///
+    /// Requires the [Ban Members] permission.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    #[inline]
+    pub async fn bans_paginated(
+        &self,
+        http: impl AsRef<Http>,
+        before: Option<UserId>,
+        after: Option<UserId>,
+        limit: Option<u16>,
+    ) -> Result<Vec<Ban>> {
+        self.id.bans_paginated(&http, before, after, limit).await
+    }
+
+    /// Gets the ban of a single user, returning `None` if the user is not
+    /// banned.
+    ///
+    /// Requires the [Ban Members] permission.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    #[inline]
+    pub async fn get_ban(&self, http: impl AsRef<Http>, user_id: impl Into<UserId>) -> Result<Option<Ban>> {
+        self.id.get_ban(&http, user_id).await
+    }
+
     /// Gets all of the guild's channels over the REST API.
     #[inline]
     pub async fn channels(&self, http: impl AsRef<Http>) -> Result<HashMap<ChannelId, GuildChannel>> {
@@ -226,6 +254,20 @@ impl PartialGuild {
         self.id.delete_role(&http, role_id).await
     }
 
+    /// Deletes a [`Role`] by Id from the guild with a reason shown in the
+    /// guild's audit log. Refer to [`delete_role`] for further documentation.
+    ///
+    /// [`delete_role`]: Self::delete_role
+    #[inline]
+    pub async fn delete_role_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        reason: &str,
+    ) -> Result<()> {
+        self.id.delete_role_with_reason(&http, role_id, reason).await
+    }
+
     /// Edits the current guild with new data where specified.
     ///
     /// **Note**: Requires the current user to have the [Manage Guild]
@@ -292,6 +334,25 @@ impl PartialGuild {
         self.id.edit_member(&http, user_id, f).await
     }
 
+    /// Edits the settings of an integration of the guild.
+    ///
+    /// Refer to [`EditIntegration`] for the settings that can be changed.
+    ///
+    /// Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn edit_integration<F>(
+        &self,
+        http: impl AsRef<Http>,
+        integration_id: impl Into<IntegrationId>,
+        f: F,
+    ) -> Result<()>
+    where F: FnOnce(&mut EditIntegration) -> &mut EditIntegration
+    {
+        self.id.edit_integration(&http, integration_id, f).await
+    }
+
     /// Edits the current user's nickname for the guild.
     ///
     /// Pass `None` to reset the nickname.
@@ -339,10 +400,16 @@ impl PartialGuild {
     }
 
     /// Returns a formatted URL of the guild's icon, if the guild has an icon.
+    ///
+    /// Animated icons are returned as a `gif`, all others as a `webp`.
     pub fn icon_url(&self) -> Option<String> {
-        self.icon
-            .as_ref()
-            .map(|icon| format!(cdn!("/icons/{}/{}.webp"), self.id, icon))
+        super::icon_url(self.id, self.icon.as_ref(), None)
+    }
+
+    /// Returns a formatted URL of the guild's icon in the given format, if the
+    /// guild has an icon.
+    pub fn icon_url_with_format(&self, format: ImageFormat) -> Option<String> {
+        super::icon_url(self.id, self.icon.as_ref(), Some(format))
     }
 
     /// Gets all [`Emoji`]s of this guild via HTTP.
@@ -470,12 +537,40 @@ impl PartialGuild {
         self.id.shard_id(shard_count).await
     }
 
+    /// Calculates the permissions of a user in a channel of the guild, using
+    /// the full [`Guild`] from the cache.
+    ///
+    /// A `PartialGuild` does not hold the guild's members and channels, so
+    /// this depends on the guild being cached. Refer to
+    /// [`Guild::user_permissions_in`] for how permissions are calculated.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::GuildNotFound`] if the guild is not in the
+    /// cache.
+    #[cfg(feature = "cache")]
+    pub async fn user_permissions_in(
+        &self,
+        cache: impl AsRef<Cache>,
+        channel_id: impl Into<ChannelId>,
+        user_id: impl Into<UserId>,
+    ) -> Result<Permissions> {
+        let channel_id = channel_id.into();
+        let user_id = user_id.into();
+
+        cache
+            .as_ref()
+            .guild_field(self.id, |guild| guild.user_permissions_in(channel_id, user_id))
+            .await
+            .ok_or(Error::Model(ModelError::GuildNotFound))
+    }
+
     /// Returns the formatted URL of the guild's splash image, if one exists.
     #[inline]
     pub fn splash_url(&self) -> Option<String> {
-        self.icon
+        self.splash
             .as_ref()
-            .map(|icon| format!(cdn!("/splashes/{}/{}.webp"), self.id, icon))
+            .map(|splash| format!(cdn!("/splashes/{}/{}.webp"), self.id, splash))
     }
 
     /// Starts an integration sync for the given integration Id.
@@ -502,13 +597,14 @@ impl PartialGuild {
         self.id.unban(&http, user_id).await
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieve's the guild's vanity URL code, or `None` if the guild does
+    /// not have one.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<Option<String>> {
         self.id.vanity_url(&http).await
     }
 
@@ -597,14 +693,8 @@ impl<'de> Deserialize<'de> for PartialGuild {
             .and_then(|x| x.parse::<u64>().ok());
 
         if let Some(guild_id) = id {
-            if let Some(array) = map.get_mut("roles").and_then(|x| x.as_array_mut()) {
-                for value in array {
-                    if let Some(role) = value.as_object_mut() {
-                        role
-                            .insert("guild_id".to_string(), Value::Number(Number::from(guild_id)));
-                    }
-                }
-            }
+            insert_guild_ids(&mut map, "roles", guild_id);
+            insert_guild_ids(&mut map, "emojis", guild_id);
         }
 
         let afk_channel_id = match map.remove("afk_channel_id") {
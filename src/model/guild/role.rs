@@ -1,5 +1,12 @@
 use crate::model::prelude::*;
+use crate::model::utils::U64Visitor;
+use bitflags::__impl_bitflags;
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
 use std::cmp::Ordering;
+use std::result::Result as StdResult;
 
 #[cfg(feature = "model")]
 use crate::builder::EditRole;
@@ -70,6 +77,13 @@ pub struct Role {
     ///
     /// The `@everyone` role is usually either `-1` or `0`.
     pub position: i64,
+    /// Extra properties of the role, such as whether it is offered in the
+    /// guild's onboarding prompts.
+    ///
+    /// These are set by Discord and are empty for guilds that do not send
+    /// them.
+    #[serde(default)]
+    pub flags: RoleFlags,
 }
 
 #[cfg(feature = "model")]
@@ -147,6 +161,15 @@ impl Role {
             self.permissions.contains(permissions)
         }
     }
+
+    /// Checks whether the role is positioned strictly above the `other` role.
+    ///
+    /// Roles sharing a position are not above one another. Use the [`Ord`]
+    /// implementation for a total ordering that breaks ties by Id.
+    #[inline]
+    pub fn is_above(&self, other: &Role) -> bool {
+        self.position > other.position
+    }
 }
 
 impl Display for Role {
@@ -169,6 +192,52 @@ impl Ord for Role {
     }
 }
 
+/// Describes extra properties of a [`Role`].
+///
+/// Flags unknown to the library are kept as-is, so that they survive being
+/// deserialized and serialized again.
+#[derive(Copy, PartialEq, Eq, Clone, PartialOrd, Ord, Hash)]
+pub struct RoleFlags {
+    pub bits: u64,
+}
+
+__impl_bitflags! {
+    RoleFlags: u64 {
+        /// The role can be selected by members in an onboarding prompt.
+        IN_PROMPT = 0b0000_0000_0000_0000_0000_0000_0000_0001;
+    }
+}
+
+impl RoleFlags {
+    /// Shorthand for checking that the set of flags contains the
+    /// [In Prompt] flag.
+    ///
+    /// [In Prompt]: Self::IN_PROMPT
+    pub fn in_prompt(self) -> bool { self.contains(Self::IN_PROMPT) }
+}
+
+impl Default for RoleFlags {
+    fn default() -> Self { Self::empty() }
+}
+
+impl<'de> Deserialize<'de> for RoleFlags {
+    fn deserialize<D>(deserializer: D) -> StdResult<Self, D::Error>
+    where D: Deserializer<'de>
+    {
+        Ok(RoleFlags {
+            bits: deserializer.deserialize_u64(U64Visitor)?,
+        })
+    }
+}
+
+impl Serialize for RoleFlags {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+    where S: Serializer
+    {
+        serializer.serialize_u64(self.bits)
+    }
+}
+
 impl PartialEq for Role {
     fn eq(&self, other: &Role) -> bool { self.id == other.id }
 }
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+
+/// A snapshot of a guild's channels, roles and settings, which can be used to
+/// create new guilds from.
+///
+/// Refer to [`Guild::create_from_template`] for creating a guild from one.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildTemplate {
+    /// The code of the template, which is used to create guilds from it.
+    pub code: String,
+    /// The name of the template.
+    pub name: String,
+    /// The description of the template, if any.
+    pub description: Option<String>,
+    /// The number of times the template has been used.
+    pub usage_count: u64,
+    /// The Id of the user who created the template.
+    pub creator_id: UserId,
+    /// The user who created the template.
+    pub creator: User,
+    /// When the template was created.
+    pub created_at: DateTime<Utc>,
+    /// When the template was last synced to its source guild.
+    pub updated_at: DateTime<Utc>,
+    /// The Id of the guild the template was created from.
+    pub source_guild_id: GuildId,
+    /// The snapshot of the source guild, as raw data.
+    pub serialized_source_guild: Value,
+    /// Whether the source guild has changes that are not synced to the
+    /// template yet.
+    pub is_dirty: Option<bool>,
+}
@@ -278,6 +278,28 @@ pub struct Maintenance {
     pub stop: String,
 }
 
+/// The file format of an image served from Discord's CDN.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    Gif,
+    Jpeg,
+    Png,
+    WebP,
+}
+
+impl ImageFormat {
+    /// The file extension used for the format in CDN URLs.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Gif => "gif",
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::Png => "png",
+            ImageFormat::WebP => "webp",
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::model::prelude::*;
@@ -316,6 +338,7 @@ mod test {
             });
             let emoji = Emoji {
                 animated: false,
+                guild_id: Some(GuildId(1)),
                 id: EmojiId(5),
                 name: "a".to_string(),
                 managed: true,
@@ -332,6 +355,7 @@ mod test {
                 name: "fake role".to_string(),
                 permissions: Permissions::empty(),
                 position: 1,
+                flags: RoleFlags::default(),
             };
             let user = User {
                 id: UserId(6),
@@ -339,8 +363,10 @@ mod test {
                 bot: false,
                 discriminator: 4132,
                 name: "fake".to_string(),
+                global_name: None,
             };
             let member = Member {
+                avatar: None,
                 deaf: false,
                 guild_id: GuildId(2),
                 joined_at: None,
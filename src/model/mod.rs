@@ -20,7 +20,7 @@
 //! ```
 
 #[macro_use]
-mod utils;
+pub(crate) mod utils;
 
 pub mod application;
 pub mod channel;
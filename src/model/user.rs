@@ -24,7 +24,6 @@ use crate::collector::{
     CollectReaction, ReactionCollectorBuilder,
     CollectReply, MessageCollectorBuilder,
 };
-use futures::future::{BoxFuture, FutureExt};
 #[cfg(feature = "model")]
 use crate::http::{Http, CacheHttp};
 
@@ -397,6 +396,13 @@ pub struct User {
     /// change if the username+discriminator pair becomes non-unique.
     #[serde(rename = "username")]
     pub name: String,
+    /// The user's display name, if they have set one.
+    ///
+    /// This is shown instead of the [`name`] in most places.
+    ///
+    /// [`name`]: Self::name
+    #[serde(default)]
+    pub global_name: Option<String>,
 }
 
 impl Default for User {
@@ -413,6 +419,7 @@ impl Default for User {
             bot: true,
             discriminator: 1432,
             name: "test".to_string(),
+            global_name: None,
         }
     }
 }
@@ -594,53 +601,37 @@ impl User {
     /// ```
     ///
     /// [`Cache`]: crate::cache::Cache
-    #[inline]
     pub async fn has_role(
         &self,
         cache_http: impl CacheHttp,
         guild: impl Into<GuildContainer>,
         role: impl Into<RoleId>
     ) -> Result<bool> {
-        self._has_role(&cache_http, guild.into(), role.into()).await
-    }
+        let guild_id = guild.into().id();
+        let role = role.into();
 
-    fn _has_role<'a>(
-        &'a self,
-        cache_http: &'a impl CacheHttp,
-        guild: GuildContainer,
-        role: RoleId
-    ) -> BoxFuture<'a, Result<bool>> {
-        async move {
-            match guild {
-                GuildContainer::Guild(partial_guild) => {
-                    self._has_role(cache_http, GuildContainer::Id(partial_guild.id), role).await
-                },
-                GuildContainer::Id(guild_id) => {
-                    // Silences a warning when compiling without the `cache` feature.
-                    #[allow(unused_mut)]
-                    let mut has_role = None;
-
-                    #[cfg(feature = "cache")]
-                    {
-                        if let Some(cache) = cache_http.cache() {
-                            if let Some(member) = cache.member(guild_id, self.id).await {
-                                has_role = Some(member.roles.contains(&role));
-                            }
-                        }
-                    }
+        // Silences a warning when compiling without the `cache` feature.
+        #[allow(unused_mut)]
+        let mut has_role = None;
 
-                    if let Some(has_role) = has_role {
-                        Ok(has_role)
-                    } else {
-                        cache_http
-                            .http()
-                            .get_member(guild_id.0, self.id.0)
-                            .await
-                            .map(|m| m.roles.contains(&role))
-                    }
-                },
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(member) = cache.member(guild_id, self.id).await {
+                    has_role = Some(member.roles.contains(&role));
+                }
             }
-        }.boxed()
+        }
+
+        if let Some(has_role) = has_role {
+            Ok(has_role)
+        } else {
+            cache_http
+                .http()
+                .get_member(guild_id.0, self.id.0)
+                .await
+                .map(|m| m.roles.contains(&role))
+        }
     }
 
     /// Refreshes the information about the user.
@@ -816,6 +807,7 @@ impl From<CurrentUser> for User {
             discriminator: user.discriminator,
             id: user.id,
             name: user.name,
+            global_name: None,
         }
     }
 }
@@ -828,6 +820,7 @@ impl<'a> From<&'a CurrentUser> for User {
             discriminator: user.discriminator,
             id: user.id,
             name: user.name.clone(),
+            global_name: None,
         }
     }
 }
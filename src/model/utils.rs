@@ -6,8 +6,6 @@ use std::{
     hash::Hash,
 };
 use super::prelude::*;
-
-#[cfg(feature = "cache")]
 use crate::internal::prelude::*;
 
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -19,6 +17,23 @@ pub fn default_true() -> bool {
     true
 }
 
+/// Inserts the Id of the guild an object belongs to, as Discord omits it from
+/// objects such as emojis or channels nested in a guild payload.
+pub fn insert_guild_id(value: &mut Value, guild_id: u64) {
+    if let Some(map) = value.as_object_mut() {
+        map.insert("guild_id".to_string(), Value::Number(Number::from(guild_id)));
+    }
+}
+
+/// Inserts the guild Id into each object of the array stored at `key`.
+pub fn insert_guild_ids(map: &mut JsonMap, key: &str, guild_id: u64) {
+    if let Some(array) = map.get_mut(key).and_then(|x| x.as_array_mut()) {
+        for value in array {
+            insert_guild_id(value, guild_id);
+        }
+    }
+}
+
 pub fn deserialize_emojis<'de, D: Deserializer<'de>>(
     deserializer: D)
     -> StdResult<HashMap<EmojiId, Emoji>, D::Error> {
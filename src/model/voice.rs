@@ -91,6 +91,8 @@ impl<'de> Deserialize<'de> for VoiceState {
         #[derive(Deserialize)]
         #[non_exhaustive]
         struct PartialMember {
+            #[serde(default)]
+            avatar: Option<String>,
             deaf: bool,
             joined_at: Option<DateTime<Utc>>,
             mute: bool,
@@ -160,6 +162,7 @@ impl<'de> Deserialize<'de> for VoiceState {
                             let partial_member: Option<PartialMember> = map.next_value()?;
                             if let Some(partial_member) = partial_member {
                                 member = Some(Member {
+                                    avatar: partial_member.avatar,
                                     deaf: partial_member.deaf,
                                     guild_id: GuildId(0),
                                     joined_at: partial_member.joined_at,
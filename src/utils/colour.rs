@@ -261,6 +261,19 @@ impl From<(u8, u8, u8)> for Colour {
     }
 }
 
+impl From<Colour> for u64 {
+    /// Converts a Colour into the integer Discord expects for colour fields.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// assert_eq!(u64::from(Colour::from_rgb(100, 76, 67)), 6573123);
+    /// ```
+    fn from(colour: Colour) -> u64 { u64::from(colour.0) }
+}
+
 colour! {
     /// Creates a new `Colour`, setting its RGB value to `(111, 198, 226)`.
     BLITZ_BLUE, blitz_blue, 0x6FC6E2;
@@ -245,6 +245,7 @@ fn dummy_message() -> Message {
             bot: false,
             discriminator: 0x0000,
             name: String::new(),
+            global_name: None,
         },
         channel_id: ChannelId::default(),
         content: String::new(),
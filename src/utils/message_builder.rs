@@ -1316,6 +1316,7 @@ mod test {
         let content_emoji = MessageBuilder::new()
             .emoji(&Emoji {
                 animated: false,
+                guild_id: None,
                 id: EmojiId(32),
                 name: "Rohrkatze".to_string(),
                 managed: false,
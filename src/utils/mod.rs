@@ -21,6 +21,7 @@ use crate::internal::prelude::*;
 use crate::model::{
     misc::EmojiIdentifier,
     id::EmojiId,
+    ModelError,
 };
 #[cfg(feature = "cache")]
 use crate::model::id::{
@@ -349,7 +350,7 @@ pub fn read_image<P: AsRef<Path>>(path: P) -> Result<String> {
 fn _read_image(path: &Path) -> Result<String> {
     let mut v = Vec::default();
     let mut f = File::open(path)?;
-    let _ = f.read_to_end(&mut v);
+    f.read_to_end(&mut v)?;
 
     let b64 = base64::encode(&v);
     let ext = if path.extension() == Some(OsStr::new("png")) {
@@ -361,6 +362,49 @@ fn _read_image(path: &Path) -> Result<String> {
     Ok(format!("data:image/{};base64,{}", ext, b64))
 }
 
+/// Checks that base64 image data - as produced by [`read_image`] - declares a
+/// supported image type and that its decoded size is at most `max_size` bytes.
+///
+/// The supported types are `png`, `jpeg`, and `gif`.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::validate_image;
+///
+/// assert!(validate_image("data:image/png;base64,aGVsbG8=", 1024).is_ok());
+/// assert!(validate_image("data:image/bmp;base64,aGVsbG8=", 1024).is_err());
+/// assert!(validate_image("data:image/png;base64,aGVsbG8=", 4).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns a [`ModelError::InvalidImageData`] if the data is not a data URI
+/// of a supported type, or if it decodes to more than `max_size` bytes.
+///
+/// [`ModelError::InvalidImageData`]: crate::model::ModelError::InvalidImageData
+pub fn validate_image(data: &str, max_size: usize) -> Result<()> {
+    let invalid = || Error::Model(ModelError::InvalidImageData);
+
+    let rest = data.strip_prefix("data:image/").ok_or_else(invalid)?;
+    let pos = rest.find(";base64,").ok_or_else(invalid)?;
+
+    match &rest[..pos] {
+        "png" | "jpeg" | "jpg" | "gif" => {},
+        _ => return Err(invalid()),
+    }
+
+    let payload = &rest[pos + 8..];
+    let padding = payload.bytes().rev().take_while(|&b| b == b'=').count();
+    let size = (payload.len() * 3 / 4).saturating_sub(padding);
+
+    if size > max_size {
+        return Err(invalid());
+    }
+
+    Ok(())
+}
+
 /// Turns a string into a vector of string arguments, splitting by spaces, but
 /// parsing content within quotes as one individual argument.
 ///
@@ -796,6 +840,15 @@ mod test {
         assert_eq!(emoji.id, 12_345);
     }
 
+    #[test]
+    fn test_image_validation() {
+        assert!(validate_image("data:image/png;base64,aGVsbG8=", 5).is_ok());
+        assert!(validate_image("data:image/jpg;base64,aGVsbG8=", 5).is_ok());
+        assert!(validate_image("data:image/gif;base64,aGVsbG8=", 4).is_err());
+        assert!(validate_image("data:image/webp;base64,aGVsbG8=", 5).is_err());
+        assert!(validate_image("aGVsbG8=", 5).is_err());
+    }
+
     #[test]
     fn test_quote_parser() {
         let parsed = parse_quotes("a \"b c\" d\"e f\"  g");
@@ -822,6 +875,7 @@ mod test {
             bot: false,
             discriminator: 0000,
             name: "Crab".to_string(),
+            global_name: None,
         };
 
         let mut guild = Guild {
@@ -835,10 +889,10 @@ mod test {
             features: Vec::new(),
             icon: None,
             id: GuildId(381880193251409931),
-            joined_at: DateTime::parse_from_str(
+            joined_at: Some(DateTime::parse_from_str(
                 "1983 Apr 13 12:09:14.274 +0000",
                 "%Y %b %d %H:%M:%S%.3f %z").unwrap()
-                .with_timezone(&Utc),
+                .with_timezone(&Utc)),
             large: false,
             member_count: 1,
             members: HashMap::new(),
@@ -850,6 +904,7 @@ mod test {
             roles: HashMap::new(),
             splash: None,
             system_channel_id: None,
+            unavailable: false,
             verification_level: VerificationLevel::None,
             voice_states: HashMap::new(),
             description: None,
@@ -861,6 +916,7 @@ mod test {
         };
 
         let member = Member {
+            avatar: None,
             deaf: false,
             guild_id: guild.id,
             joined_at: None,
@@ -880,6 +936,7 @@ mod test {
             name: "ferris-club-member".to_string(),
             permissions: Permissions::all(),
             position: 0,
+            flags: RoleFlags::default(),
         };
 
         let channel = GuildChannel {